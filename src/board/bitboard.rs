@@ -0,0 +1,223 @@
+use crate::{Color, Connect4Error};
+
+/// A compact bitboard representation of a game board intended for search-based engines.
+///
+/// Each `Color` owns a single `u128` mask in which every column takes up `num_rows + 1` bits,
+/// starting from the bottom of the column. The extra sentinel bit at the top of each column is
+/// never set, which keeps runs from wrapping around into the next column when the masks are
+/// shifted during win detection.
+///
+/// A `BitBoard` can only represent boards where `(num_rows + 1) * num_columns` fits in the 128
+/// bits of the masks; `BitBoard::new` returns `None` for anything larger.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BitBoard {
+    /// The number of rows in the board.
+    pub num_rows: usize,
+    /// The number of columns in the board.
+    pub num_columns: usize,
+    /// The masks of the occupied cells of each `Color`, indexed by `Color::index`.
+    masks: [u128; Color::COUNT],
+    /// The number of pieces currently in each column.
+    heights: Vec<usize>,
+}
+
+/// Returns `true` if `mask` contains `amount_to_win` consecutive bits spaced `shift` bits apart.
+fn has_run(mask: u128, shift: usize, amount_to_win: usize) -> bool {
+    let mut run = mask;
+    for _ in 1..amount_to_win {
+        run &= run.checked_shr(shift as u32).unwrap_or(0);
+        if run == 0 {
+            return false;
+        }
+    }
+    run != 0
+}
+
+impl BitBoard {
+    /// The number of bits available in each color mask.
+    pub const MAX_BITS: usize = u128::BITS as usize;
+
+    /// Returns `true` if a board with `num_rows` rows and `num_columns` columns can be
+    /// represented as a `BitBoard`.
+    pub fn fits(num_rows: usize, num_columns: usize) -> bool {
+        num_rows > 0
+            && num_columns > 0
+            && (num_rows + 1)
+                .checked_mul(num_columns)
+                .is_some_and(|bits| bits <= Self::MAX_BITS)
+    }
+
+    /// Constructs an empty `BitBoard` instance.
+    ///
+    /// Returns `None` if the dimensions do not fit in the masks (see `BitBoard::fits`).
+    pub fn new(num_rows: usize, num_columns: usize) -> Option<Self> {
+        if !Self::fits(num_rows, num_columns) {
+            return None;
+        }
+        Some(BitBoard {
+            num_rows,
+            num_columns,
+            masks: [0; Color::COUNT],
+            heights: vec![0; num_columns],
+        })
+    }
+
+    /// Returns the bit of the cell in the column with index `col_index` that is `height` cells
+    /// above the bottom of the board.
+    fn bit(&self, height: usize, col_index: usize) -> u128 {
+        1 << (col_index * (self.num_rows + 1) + height)
+    }
+
+    /// Returns the mask of all cells occupied by pieces of `Color` `color`.
+    pub fn get_mask(&self, color: Color) -> u128 {
+        self.masks[color.index()]
+    }
+
+    /// Returns the `Color` of the piece in the cell at `row_index` (counted from the top, as in
+    /// `Board::get_data`) and `col_index`, or `None` if the cell is empty.
+    pub fn get_cell(&self, row_index: usize, col_index: usize) -> Option<Color> {
+        let bit = self.bit(self.num_rows - 1 - row_index, col_index);
+        if self.get_occupied() & bit == 0 {
+            return None;
+        }
+        Color::ALL
+            .into_iter()
+            .find(|color| self.get_mask(*color) & bit != 0)
+    }
+
+    /// Returns the mask of all occupied cells.
+    pub fn get_occupied(&self) -> u128 {
+        self.masks.iter().fold(0, |acc, mask| acc | mask)
+    }
+
+    /// Returns the number of pieces in the column with index `col_index`.
+    pub fn get_height(&self, col_index: usize) -> usize {
+        self.heights[col_index]
+    }
+
+    /// Returns `true` if the column with index `col_index` is available else `false`.
+    pub fn available_column(&self, col_index: usize) -> bool {
+        col_index < self.num_columns && self.heights[col_index] < self.num_rows
+    }
+
+    /// Returns a `Result` type with a unit `Ok` if the column with index `col_index` exists or an
+    /// `Err` with `Connect4Error::ColumnOutOfRange` otherwise.
+    fn check_column(&self, col_index: usize) -> Result<(), Connect4Error> {
+        if col_index >= self.num_columns {
+            return Err(Connect4Error::ColumnOutOfRange {
+                col_index,
+                num_columns: self.num_columns,
+            });
+        }
+        Ok(())
+    }
+
+    /// Drops a game piece of `Color` `color` in the column with index `col_index`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the row index (counted from the top, as in
+    /// `Board::get_data`) the piece landed in or an `Err` with a `Connect4Error` if the column
+    /// with index `col_index` does not exist or is full.
+    pub fn drop_piece(&mut self, color: Color, col_index: usize) -> Result<usize, Connect4Error> {
+        self.check_column(col_index)?;
+        if self.heights[col_index] == self.num_rows {
+            return Err(Connect4Error::ColumnFull { col_index });
+        }
+        let height = self.heights[col_index];
+        self.masks[color.index()] |= self.bit(height, col_index);
+        self.heights[col_index] += 1;
+        Ok(self.num_rows - 1 - height)
+    }
+
+    /// Removes the highest game piece from the column with index `col_index`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Color` of the removed piece or an
+    /// `Err` with a `Connect4Error` if the column with index `col_index` does not exist or is
+    /// empty.
+    pub fn undo_piece(&mut self, col_index: usize) -> Result<Color, Connect4Error> {
+        self.check_column(col_index)?;
+        if self.heights[col_index] == 0 {
+            return Err(Connect4Error::ColumnEmpty { col_index });
+        }
+        let height = self.heights[col_index] - 1;
+        let color = self
+            .get_cell(self.num_rows - 1 - height, col_index)
+            .ok_or_else(|| {
+                Connect4Error::InvalidState(format!(
+                    "The column with index {col_index} is corrupted."
+                ))
+            })?;
+        self.masks[color.index()] &= !self.bit(height, col_index);
+        self.heights[col_index] -= 1;
+        Ok(color)
+    }
+
+    /// Returns a `bool` indicating whether the entire board is full of game pieces.
+    pub fn is_full(&self) -> bool {
+        self.heights.iter().all(|height| *height == self.num_rows)
+    }
+
+    /// Returns `true` if the pieces of `Color` `color` contain a run of `amount_to_win` pieces
+    /// in any direction.
+    pub fn has_won(&self, color: Color, amount_to_win: usize) -> bool {
+        let mask = self.get_mask(color);
+        if amount_to_win == 0 || mask == 0 {
+            return false;
+        }
+        let column_bits = self.num_rows + 1;
+        // vertical, horizontal and both diagonals
+        [1, column_bits, column_bits - 1, column_bits + 1]
+            .into_iter()
+            .any(|shift| has_run(mask, shift, amount_to_win))
+    }
+
+    /// Returns an `Option` type containing `Some<Color>` if a team is in a winning condition or
+    /// `None` if no teams are in a winning condition.
+    pub fn get_winning_color(&self, amount_to_win: usize) -> Option<Color> {
+        Color::ALL
+            .into_iter()
+            .find(|color| self.has_won(*color, amount_to_win))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_do_not_wrap_between_columns() {
+        let mut bits = BitBoard::new(6, 7).unwrap();
+        // the top two cells of column 1 and the bottom two of column 2 are adjacent bits
+        for _ in 0..4 {
+            bits.drop_piece(Color::BLACK, 0).unwrap();
+        }
+        for _ in 0..2 {
+            bits.drop_piece(Color::RED, 0).unwrap();
+            bits.drop_piece(Color::RED, 1).unwrap();
+        }
+        assert!(!bits.has_won(Color::RED, 4));
+        assert!(bits.has_won(Color::BLACK, 4));
+    }
+
+    #[test]
+    fn full_columns_are_unavailable() {
+        let mut bits = BitBoard::new(2, 3).unwrap();
+        assert_eq!(bits.drop_piece(Color::RED, 1).unwrap(), 1);
+        assert_eq!(bits.drop_piece(Color::BLACK, 1).unwrap(), 0);
+        assert!(!bits.available_column(1));
+        assert!(matches!(
+            bits.drop_piece(Color::RED, 1),
+            Err(Connect4Error::ColumnFull { col_index: 1 })
+        ));
+        assert!(matches!(
+            bits.drop_piece(Color::RED, 3),
+            Err(Connect4Error::ColumnOutOfRange { col_index: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn oversized_boards_do_not_fit() {
+        assert!(BitBoard::fits(15, 8));
+        assert!(!BitBoard::fits(16, 8));
+        assert!(BitBoard::new(0, 7).is_none());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::Connect4Error;
+
+/// The name, symbol and RGB value of every built-in team, indexed by `Color::index`.
+const TEAMS: [(&str, char, (u8, u8, u8)); Color::COUNT] = [
+    ("Red", 'R', (220, 40, 40)),
+    ("Black", 'B', (120, 120, 120)),
+    ("Yellow", 'Y', (240, 200, 30)),
+    ("Green", 'G', (40, 170, 60)),
+    ("Cyan", 'C', (40, 190, 210)),
+    ("Purple", 'P', (140, 60, 190)),
+    ("Orange", 'O', (240, 130, 30)),
+    ("White", 'W', (235, 235, 235)),
+    ("Magenta", 'M', (220, 50, 180)),
+    ("Teal", 'T', (20, 130, 130)),
+    ("Lime", 'L', (160, 220, 40)),
+    ("Navy", 'N', (40, 60, 150)),
+    ("Silver", 'S', (180, 180, 190)),
+    ("Amber", 'A', (255, 190, 0)),
+    ("Violet", 'V', (170, 120, 230)),
+    ("Indigo", 'I', (80, 40, 160)),
+];
+
+/// The team a piece or a player belongs to.
+///
+/// A game can be played by any number of teams up to `Color::COUNT` (16), the number of
+/// built-in colors, which is also the number of masks a `BitBoard` keeps; there is no way to
+/// construct a seventeenth `Color`, so larger games cannot be configured. Every team has a display
+/// name (e.g. `Red`), a single character symbol used in compact notations (see `Color::symbol`)
+/// and the RGB value its discs are drawn in (see `Color::rgb`). A standard game is played by
+/// `Color::RED` and `Color::BLACK`.
+///
+/// # Examples
+///
+/// ```
+/// use connect_4::Color;
+///
+/// let color: Color = "yellow".parse().unwrap();
+/// assert_eq!(color, Color::YELLOW);
+/// assert_eq!(color.index(), 2);
+/// assert_eq!(color.symbol(), 'Y');
+/// assert_eq!(Color::new(2), Some(Color::YELLOW));
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub struct Color(u8);
+
+impl Color {
+    /// The number of teams available, which is the most teams a game can have.
+    pub const COUNT: usize = 16;
+
+    pub const RED: Color = Color(0);
+    pub const BLACK: Color = Color(1);
+    pub const YELLOW: Color = Color(2);
+    pub const GREEN: Color = Color(3);
+    pub const CYAN: Color = Color(4);
+    pub const PURPLE: Color = Color(5);
+    pub const ORANGE: Color = Color(6);
+    pub const WHITE: Color = Color(7);
+    pub const MAGENTA: Color = Color(8);
+    pub const TEAL: Color = Color(9);
+    pub const LIME: Color = Color(10);
+    pub const NAVY: Color = Color(11);
+    pub const SILVER: Color = Color(12);
+    pub const AMBER: Color = Color(13);
+    pub const VIOLET: Color = Color(14);
+    pub const INDIGO: Color = Color(15);
+
+    /// Every team in the order of their indices.
+    pub const ALL: [Color; Color::COUNT] = {
+        let mut all = [Color(0); Color::COUNT];
+        let mut index = 0;
+        while index < Color::COUNT {
+            all[index] = Color(index as u8);
+            index += 1;
+        }
+        all
+    };
+
+    /// Returns the `Color` with index `index`, or `None` if `index` is not less than
+    /// `Color::COUNT`.
+    pub fn new(index: usize) -> Option<Color> {
+        Color::ALL.get(index).copied()
+    }
+
+    /// Returns the `Color` with index `index` like `Color::new`, or an `Err` with a
+    /// `Connect4Error::InvalidConfig` naming the limit of `Color::COUNT` teams.
+    pub fn try_new(index: usize) -> Result<Color, Connect4Error> {
+        Color::new(index).ok_or_else(|| {
+            Connect4Error::InvalidConfig(format!(
+                "Games support at most {} teams, so there is no team number {}.",
+                Color::COUNT,
+                index + 1
+            ))
+        })
+    }
+
+    /// Returns the index of the color, which is unique among all colors and less than
+    /// `Color::COUNT`.
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+
+    /// Returns the display name of the color, e.g. `Red`.
+    pub fn name(&self) -> &'static str {
+        TEAMS[self.index()].0
+    }
+
+    /// Returns the single character used for the color in compact notations such as
+    /// `Board::to_grid_str`.
+    pub fn symbol(&self) -> char {
+        TEAMS[self.index()].1
+    }
+
+    /// Returns the RGB value the pieces of the color are drawn in.
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        TEAMS[self.index()].2
+    }
+
+    /// Returns the `Color` represented by the single character `symbol` (see `Color::symbol`),
+    /// ignoring case, or `None` if no color uses it.
+    pub fn from_symbol(symbol: char) -> Option<Color> {
+        Color::ALL
+            .into_iter()
+            .find(|color| color.symbol().eq_ignore_ascii_case(&symbol))
+    }
+}
+
+impl std::fmt::Debug for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Color {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::ALL
+            .into_iter()
+            .find(|color| color.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| Connect4Error::Parse(format!("Unknown color \"{}\".", s.trim())))
+    }
+}
+
+impl From<Color> for String {
+    fn from(color: Color) -> Self {
+        color.to_string()
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = Connect4Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn teams_are_found_by_index_name_and_symbol() {
+        for (index, color) in Color::ALL.into_iter().enumerate() {
+            assert_eq!(color.index(), index);
+            assert_eq!(Color::new(index), Some(color));
+            assert_eq!(color.name().parse::<Color>().unwrap(), color);
+            assert_eq!(Color::from_symbol(color.symbol()), Some(color));
+        }
+        assert_eq!(Color::new(Color::COUNT), None);
+        assert_eq!(Color::try_new(15).unwrap(), Color::INDIGO);
+        assert!(matches!(
+            Color::try_new(Color::COUNT),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+        assert_eq!(" PURPLE ".parse::<Color>().unwrap(), Color::PURPLE);
+        assert_eq!(Color::from_symbol('y'), Some(Color::YELLOW));
+        assert_eq!(Color::from_symbol('X'), None);
+        assert!(matches!(
+            "mauve".parse::<Color>(),
+            Err(Connect4Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn names_and_symbols_are_unique() {
+        for a in Color::ALL {
+            for b in Color::ALL.into_iter().filter(|b| *b != a) {
+                assert_ne!(a.name(), b.name());
+                assert_ne!(a.symbol(), b.symbol());
+            }
+        }
+    }
+}
@@ -0,0 +1,967 @@
+//! The board pieces are dropped into, along with the colors of the pieces and compact notations
+//! for positions.
+
+use crate::Connect4Error;
+
+mod bitboard;
+pub use bitboard::BitBoard;
+
+mod color;
+pub use color::Color;
+
+mod notation;
+pub use notation::{format_moves, parse_moves};
+
+mod position;
+pub use position::Position;
+
+mod team;
+pub use team::Team;
+
+use std::collections::{BTreeMap, HashSet};
+
+type Line = Vec<Option<Color>>;
+
+/// Represents the game board in which pieces are dropped.
+///
+/// Adds several functionalities for checking and changing the state of the board.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "BoardRepr", try_from = "BoardRepr")
+)]
+pub struct Board {
+    /// The raw double nested `Vec` that holds the state of the board in the form
+    /// of `Option<Color>`.
+    data: Vec<Line>,
+    /// The number of rows in the board.
+    pub num_rows: usize,
+    /// The number of columns in the board.
+    pub num_columns: usize,
+    /// The width in characters that each column should be printed as.
+    pub column_width: usize,
+    /// The height in lines that each row should be printed as.
+    pub row_height: usize,
+    /// A `BitBoard` mirror of `data` used for fast win detection, or `None` if the board is too
+    /// large to be represented as one.
+    bits: Option<BitBoard>,
+    /// The custom presentation of the teams which are not shown with their built-in one.
+    teams: BTreeMap<Color, Team>,
+}
+
+impl Board {
+    /// Constructs an empty `Board` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_rows` - a `usize` integer denoting the number of rows in the board.
+    ///
+    /// * `num_columns` - a `usize` integer denoting the number of columns in the board.
+    ///
+    /// * `row_height` - a `usize` integer denoting the width in characters that each column should
+    ///   be printed as.
+    ///
+    /// * `column_width` - a `usize` integer denoting the height in lines that each row should be
+    ///   printed as.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::Board;
+    ///
+    /// let mut default_board = Board::new(6, 7, 3, 7);
+    /// ```
+    pub fn new(
+        num_rows: usize,
+        num_columns: usize,
+        row_height: usize,
+        column_width: usize,
+    ) -> Self {
+        let mut board = Board {
+            data: Vec::new(),
+            num_rows,
+            num_columns,
+            row_height,
+            column_width,
+            bits: BitBoard::new(num_rows, num_columns),
+            teams: BTreeMap::new(),
+        };
+        for _ in 0..num_rows {
+            let mut row = Vec::new();
+            for _ in 0..num_columns {
+                row.push(None);
+            }
+            board.data.push(row);
+        }
+        board
+    }
+
+    /// Returns `true` if the column with index `col_index` is available else `false`.
+    pub fn available_column(&self, col_index: usize) -> bool {
+        if col_index >= self.num_columns {
+            false
+        } else {
+            self.get_column(col_index)[0].is_none()
+        }
+    }
+
+    /// Returns a `Result` type with an `Ok` containing the row index of the highest available slot
+    /// in the column with index `col_index` or an `Err` with a `Connect4Error` if the column with
+    /// index `col_index` does not exist or is full.
+    fn get_highest_index(&self, col_index: usize) -> Result<usize, Connect4Error> {
+        if col_index >= self.num_columns {
+            return Err(Connect4Error::ColumnOutOfRange {
+                col_index,
+                num_columns: self.num_columns,
+            });
+        }
+        if !self.available_column(col_index) {
+            return Err(Connect4Error::ColumnFull { col_index });
+        }
+        let mut highest = 0;
+        for row_index in 0..self.num_rows {
+            match self.data[row_index][col_index] {
+                Some(_) => break,
+                None => highest = row_index,
+            }
+        }
+        Ok(highest)
+    }
+
+    /// Drops a game piece of `Color` `color` in the column wiht index `col_index`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the row index the piece landed in or an
+    /// `Err` with a `Connect4Error` if the column with index `col_index` does not exist or is
+    /// full.
+    pub fn drop_piece(&mut self, color: Color, col_index: usize) -> Result<usize, Connect4Error> {
+        let row_index = self.get_highest_index(col_index)?;
+        self.data[row_index][col_index] = Some(color);
+        if let Some(bits) = &mut self.bits {
+            bits.drop_piece(color, col_index)?;
+        }
+        Ok(row_index)
+    }
+
+    /// Returns the `Color` of the piece in the cell at `row_index` (counted from the top) and
+    /// `col_index`, or `None` if the cell is empty.
+    pub fn get_cell(&self, row_index: usize, col_index: usize) -> Option<Color> {
+        self.data[row_index][col_index]
+    }
+
+    /// Removes the highest game piece from the column with index `col_index`, undoing the last
+    /// `drop_piece` made in that column.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Color` of the removed piece or an
+    /// `Err` with a `Connect4Error` if the column with index `col_index` does not exist or is
+    /// empty.
+    pub fn undo_piece(&mut self, col_index: usize) -> Result<Color, Connect4Error> {
+        if col_index >= self.num_columns {
+            return Err(Connect4Error::ColumnOutOfRange {
+                col_index,
+                num_columns: self.num_columns,
+            });
+        }
+        let (row_index, color) = (0..self.num_rows)
+            .find_map(|row_index| self.data[row_index][col_index].map(|color| (row_index, color)))
+            .ok_or(Connect4Error::ColumnEmpty { col_index })?;
+        self.data[row_index][col_index] = None;
+        if let Some(bits) = &mut self.bits {
+            bits.undo_piece(col_index)?;
+        }
+        Ok(color)
+    }
+
+    /// Returns the column of the board with index `col_index`.
+    fn get_column(&self, col_index: usize) -> Line {
+        let mut column = Vec::new();
+        for row_index in 0..self.num_rows {
+            column.push(self.data[row_index][col_index]);
+        }
+        column
+    }
+
+    /// Returns the left leaning diagonal of the board with index from the top-right `diag_index`.
+    fn get_left_diagonal(&self, diag_index: usize) -> Line {
+        let mut diagonal = Vec::new();
+        for row_index in 0..self.num_rows {
+            let col_index =
+                { row_index as isize - diag_index as isize + self.num_columns as isize - 1 };
+            if col_index >= 0 && col_index < self.num_columns as isize {
+                diagonal.push(self.data[row_index][col_index as usize]);
+            }
+        }
+        diagonal
+    }
+
+    /// Returns the right leaning diagonal of the board with index from the top-left `diag_index`.
+    fn get_right_diagonal(&self, diag_index: usize) -> Line {
+        let mut diagonal = Vec::new();
+        for row_index in 0..self.num_rows {
+            let col_index = diag_index as isize - row_index as isize;
+            if col_index >= 0 && col_index < self.num_columns as isize {
+                diagonal.push(self.data[row_index][col_index as usize]);
+            }
+        }
+        diagonal
+    }
+
+    /// Returns a `bool` indicating whether the entire board is full of game pieces.
+    pub fn is_full(&self) -> bool {
+        for item in self.data[0].iter() {
+            match item {
+                Some(_) => {}
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Returns the rows of the board from top to bottom, each holding the `Option<Color>` of its
+    /// cells from left to right.
+    ///
+    /// The rows can only be changed through `Board::drop_piece` and `Board::undo_piece`, which
+    /// keep the `BitBoard` mirror in sync with them.
+    pub fn get_data(&self) -> &[Line] {
+        &self.data
+    }
+
+    /// Returns a reference to the `BitBoard` mirror of the board, or `None` if the board is too
+    /// large to be represented as one.
+    ///
+    /// Search-based engines can clone the returned `BitBoard` to evaluate positions without the
+    /// overhead of the nested `Vec`s in `data`.
+    pub fn bitboard(&self) -> Option<&BitBoard> {
+        self.bits.as_ref()
+    }
+
+    /// Returns the `Team` the pieces of `color` are presented as: the custom one set with
+    /// `Board::set_team` or the built-in one (see `Team::default_for`).
+    pub fn get_team(&self, color: Color) -> Team {
+        self.teams
+            .get(&color)
+            .cloned()
+            .unwrap_or_else(|| Team::default_for(color))
+    }
+
+    /// Returns the custom `Team` of every color which has one, ordered by color.
+    pub fn get_custom_teams(&self) -> Vec<(Color, Team)> {
+        self.teams
+            .iter()
+            .map(|(color, team)| (*color, team.clone()))
+            .collect()
+    }
+
+    /// Presents the pieces of `color` as `team` instead of their built-in presentation.
+    pub fn set_team(&mut self, color: Color, team: Team) {
+        if team == Team::default_for(color) {
+            self.teams.remove(&color);
+        } else {
+            self.teams.insert(color, team);
+        }
+    }
+
+    /// Returns an `Option` type containing `Some<Color>` if a team is in a winning condition or
+    /// `None` if no teams are in a winning condition.
+    ///
+    /// Uses the `BitBoard` mirror when available and falls back to scanning every line of `data`
+    /// otherwise.
+    pub fn get_winning_color(&self, amount_to_win: usize) -> Option<Color> {
+        if let Some(bits) = &self.bits {
+            return bits.get_winning_color(amount_to_win);
+        }
+        let check_line = |line: &Line| -> Option<Color> {
+            let mut current: Option<Color> = None;
+            let mut count = 0;
+            for item in line {
+                let item_color;
+                if let Some(color) = item {
+                    item_color = *color;
+                } else {
+                    current = None;
+                    count = 0;
+                    continue;
+                }
+                let current_color;
+                if let Some(color) = current {
+                    current_color = color;
+                } else {
+                    current = Some(item_color);
+                    count = 1;
+                    continue;
+                }
+                if item_color == current_color {
+                    if count == amount_to_win - 1 {
+                        return Some(current_color);
+                    } else {
+                        count += 1;
+                    }
+                } else {
+                    current = Some(item_color);
+                    count = 1;
+                }
+            }
+            None
+        };
+
+        let mut lines = Vec::new();
+        lines.extend(self.data.clone());
+        for col_index in 0..self.num_columns {
+            lines.push(self.get_column(col_index));
+        }
+        for diag_index in 0..self.num_rows + self.num_columns - 1 {
+            lines.push(self.get_left_diagonal(diag_index));
+            lines.push(self.get_right_diagonal(diag_index));
+        }
+        for line in lines.iter() {
+            if let Some(color) = check_line(line) {
+                return Some(color);
+            }
+        }
+        None
+    }
+
+    /// Returns the `(row_index, col_index)` cells of every run of at least `amount_to_win` pieces
+    /// of the same `Color` on the board.
+    ///
+    /// Each run is listed from one end to the other and appears once, however long it is; a
+    /// piece can be part of several runs in different directions.
+    pub fn get_winning_lines(&self, amount_to_win: usize) -> Vec<Vec<(usize, usize)>> {
+        let in_bounds = |row: isize, col: isize| {
+            row >= 0 && col >= 0 && row < self.num_rows as isize && col < self.num_columns as isize
+        };
+        let mut lines = Vec::new();
+        for row_index in 0..self.num_rows {
+            for col_index in 0..self.num_columns {
+                let Some(color) = self.data[row_index][col_index] else {
+                    continue;
+                };
+                for (row_step, col_step) in [(0, 1), (1, 0), (1, 1), (1, -1)] {
+                    let (row, col) = (row_index as isize, col_index as isize);
+                    let (prev_row, prev_col) = (row - row_step, col - col_step);
+                    if in_bounds(prev_row, prev_col)
+                        && self.data[prev_row as usize][prev_col as usize] == Some(color)
+                    {
+                        // only count runs from their first cell
+                        continue;
+                    }
+                    let mut line = Vec::new();
+                    let (mut row, mut col) = (row, col);
+                    while in_bounds(row, col)
+                        && self.data[row as usize][col as usize] == Some(color)
+                    {
+                        line.push((row as usize, col as usize));
+                        row += row_step;
+                        col += col_step;
+                    }
+                    if line.len() >= amount_to_win.max(1) {
+                        lines.push(line);
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    /// Returns the `(row_index, col_index)` cells of the first run of at least `amount_to_win`
+    /// pieces of the same `Color` found on the board, or `None` if there is no such run.
+    ///
+    /// Cells are listed from one end of the run to the other.
+    pub fn get_winning_line(&self, amount_to_win: usize) -> Option<Vec<(usize, usize)>> {
+        self.get_winning_lines(amount_to_win).into_iter().next()
+    }
+
+    /// Constructs a `Board` with `num_rows` rows and `num_columns` columns by playing the moves
+    /// in `moves_str` with Red moving first and the colors alternating.
+    ///
+    /// `moves_str` uses the 1-based column notation of `format_moves` (e.g. `"4453"`). Positions
+    /// of games with other teams or turn orders can be rebuilt with `GameRecord::to_board`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Board` instance or an `Err` with a
+    /// `Connect4Error::InvalidConfig` if the board has no rows or no columns, or a
+    /// `Connect4Error::Parse` naming the first move which is not a column number or overfills its
+    /// column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::Board;
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let board = Board::from_moves("4453", 6, 7)?;
+    /// assert_eq!(board.to_grid_str(), ".......\n.......\n.......\n.......\n...B...\n..BRR..");
+    /// let board = Board::from_moves("1111", 4, 5)?;
+    /// assert_eq!(board.to_grid_str(), "B....\nR....\nB....\nR....");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_moves(
+        moves_str: &str,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> Result<Self, Connect4Error> {
+        if num_rows == 0 || num_columns == 0 {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "The board must have at least one row and one column (got {}x{}).",
+                num_rows, num_columns
+            )));
+        }
+        let mut board = Board::new(num_rows, num_columns, 3, 7);
+        let mut color = Color::RED;
+        for (i, col_index) in parse_moves(moves_str, board.num_columns)?
+            .into_iter()
+            .enumerate()
+        {
+            if !board.available_column(col_index) {
+                return Err(Connect4Error::Parse(format!(
+                    "Move {} overfills column {} which already holds {} pieces.",
+                    i + 1,
+                    col_index + 1,
+                    board.num_rows
+                )));
+            }
+            board.drop_piece(color, col_index)?;
+            color = if color == Color::RED {
+                Color::BLACK
+            } else {
+                Color::RED
+            };
+        }
+        Ok(board)
+    }
+
+    /// Returns the move notation (see `format_moves`) of a sequence of alternating moves starting
+    /// with Red which reaches the board's position, or `None` if no such sequence exists.
+    ///
+    /// The order of the moves is not stored on the board, so when several sequences reach the
+    /// position the one that plays the lowest column number first at each step is returned.
+    pub fn to_moves(&self) -> Option<String> {
+        let column_heights = (0..self.num_columns)
+            .map(|col_index| {
+                (0..self.num_rows)
+                    .take_while(|row_index| {
+                        self.data[self.num_rows - 1 - row_index][col_index].is_some()
+                    })
+                    .count()
+            })
+            .collect::<Vec<usize>>();
+        let num_pieces = column_heights.iter().sum::<usize>();
+        let mut heights = vec![0; self.num_columns];
+        let mut moves = Vec::new();
+        let mut visited = HashSet::new();
+        if self.find_moves(
+            &column_heights,
+            num_pieces,
+            &mut heights,
+            &mut moves,
+            &mut visited,
+        ) {
+            Some(format_moves(&moves, self.num_columns))
+        } else {
+            None
+        }
+    }
+
+    /// Searches for alternating moves which fill every column up to `column_heights` from the
+    /// partially replayed position where each column holds `heights` pieces, appending them to
+    /// `moves`.
+    ///
+    /// `visited` holds the partial positions which are already known to be dead ends.
+    fn find_moves(
+        &self,
+        column_heights: &[usize],
+        num_pieces: usize,
+        heights: &mut Vec<usize>,
+        moves: &mut Vec<usize>,
+        visited: &mut HashSet<Vec<usize>>,
+    ) -> bool {
+        if moves.len() == num_pieces {
+            return true;
+        }
+        if visited.contains(heights) {
+            return false;
+        }
+        let color = if moves.len().is_multiple_of(2) {
+            Color::RED
+        } else {
+            Color::BLACK
+        };
+        for col_index in 0..self.num_columns {
+            if heights[col_index] == column_heights[col_index]
+                || self.data[self.num_rows - 1 - heights[col_index]][col_index] != Some(color)
+            {
+                continue;
+            }
+            heights[col_index] += 1;
+            moves.push(col_index);
+            if self.find_moves(column_heights, num_pieces, heights, moves, visited) {
+                return true;
+            }
+            moves.pop();
+            heights[col_index] -= 1;
+        }
+        visited.insert(heights.clone());
+        false
+    }
+
+    /// Constructs a `Board` from the grid notation produced by `Board::to_grid_str`.
+    ///
+    /// Each non-empty line of `grid_str` is a row from top to bottom holding one character per
+    /// cell: `.` for an empty cell or the `Color::symbol` of a piece (e.g. `R` or `B`, in any
+    /// case). Whitespace around lines is ignored. The board takes its size from the grid and is
+    /// printed with 3 line rows and 7 character columns.
+    ///
+    /// Teams take turns in the order of their `Color::index` starting with Red, so every team
+    /// must hold as many pieces as each later team up to the last one in the grid, or one more.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Board` instance or an `Err` with a
+    /// `Connect4Error::Parse` describing the first problem found, such as rows of different
+    /// lengths, unknown characters, pieces floating above empty cells or impossible piece counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::{Board, Color};
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let board = Board::from_grid_str(
+    ///     ".......
+    ///      .......
+    ///      .......
+    ///      .......
+    ///      ...B...
+    ///      ..BRR..",
+    /// )?;
+    /// assert_eq!(board.get_cell(5, 3), Some(Color::RED));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_grid_str(grid_str: &str) -> Result<Self, Connect4Error> {
+        let board = Self::parse_grid(grid_str)?;
+        let count = |color: Color| {
+            board
+                .data
+                .iter()
+                .flatten()
+                .filter(|cell| **cell == Some(color))
+                .count()
+        };
+        let num_teams = Color::ALL
+            .into_iter()
+            .rposition(|color| count(color) > 0)
+            .map_or(2, |index| (index + 1).max(2));
+        let counts = Color::ALL[..num_teams]
+            .iter()
+            .map(|color| (*color, count(*color)))
+            .collect::<Vec<(Color, usize)>>();
+        let possible = counts.windows(2).all(|pair| pair[0].1 >= pair[1].1)
+            && counts[0].1 <= counts[num_teams - 1].1 + 1;
+        if !possible {
+            let counts = counts
+                .iter()
+                .map(|(color, count)| format!("{count} {color}"))
+                .collect::<Vec<String>>()
+                .join(", ");
+            return Err(Connect4Error::Parse(format!(
+                "Impossible piece counts: {counts} (teams move in color order starting with Red, \
+                 so each team must have as many pieces as the teams after it or one more)."
+            )));
+        }
+        Ok(board)
+    }
+
+    /// Constructs a `Board` from the grid notation `grid_str` like `Board::from_grid_str` but
+    /// without checking the piece counts, since games need not start with Red.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Board` instance or an `Err` with a
+    /// `Connect4Error::Parse` describing the first malformed row or floating piece.
+    fn parse_grid(grid_str: &str) -> Result<Self, Connect4Error> {
+        let rows = grid_str
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>();
+        let num_columns = rows
+            .first()
+            .ok_or_else(|| Connect4Error::Parse("The grid is empty.".into()))?
+            .chars()
+            .count();
+        let mut data: Vec<Line> = Vec::new();
+        for (row_index, row) in rows.iter().enumerate() {
+            let cells = row
+                .chars()
+                .enumerate()
+                .map(|(col_index, symbol)| match symbol {
+                    '.' => Ok(None),
+                    _ => Color::from_symbol(symbol).map(Some).ok_or_else(|| {
+                        Connect4Error::Parse(format!(
+                            "Row {}, column {}: unknown cell '{}' (expected '.' or a color \
+                             symbol such as 'R' or 'B').",
+                            row_index + 1,
+                            col_index + 1,
+                            symbol
+                        ))
+                    }),
+                })
+                .collect::<Result<Line, Connect4Error>>()?;
+            if cells.len() != num_columns {
+                return Err(Connect4Error::Parse(format!(
+                    "Row {} has {} cells but row 1 has {}.",
+                    row_index + 1,
+                    cells.len(),
+                    num_columns
+                )));
+            }
+            data.push(cells);
+        }
+        let num_rows = data.len();
+        for (row_index, rows) in data.windows(2).enumerate() {
+            for (col_index, (cell, below)) in rows[0].iter().zip(rows[1].iter()).enumerate() {
+                if cell.is_some() && below.is_none() {
+                    return Err(Connect4Error::Parse(format!(
+                        "The piece at row {}, column {} is floating above an empty cell.",
+                        row_index + 1,
+                        col_index + 1
+                    )));
+                }
+            }
+        }
+        let mut board = Board::new(num_rows, num_columns, 3, 7);
+        for row in data.iter().rev() {
+            for (col_index, cell) in row.iter().enumerate() {
+                if let Some(color) = cell {
+                    board.drop_piece(*color, col_index)?;
+                }
+            }
+        }
+        Ok(board)
+    }
+
+    /// Returns the grid notation of the board which can be parsed back with
+    /// `Board::from_grid_str`: one line per row from top to bottom with `.` for an empty cell and
+    /// the `Color::symbol` of each piece.
+    pub fn to_grid_str(&self) -> String {
+        self.data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.map_or('.', |color| color.symbol()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// The serialized form of a `Board`, which leaves out the `BitBoard` mirror so that it can be
+/// rebuilt (and the cells validated) when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BoardRepr {
+    num_rows: usize,
+    num_columns: usize,
+    column_width: usize,
+    row_height: usize,
+    /// The rows of the board from top to bottom in the notation of `Board::to_grid_str`.
+    grid: Vec<String>,
+    /// The custom presentation of the teams, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    teams: Vec<(Color, Team)>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Board> for BoardRepr {
+    fn from(board: Board) -> Self {
+        BoardRepr {
+            num_rows: board.num_rows,
+            num_columns: board.num_columns,
+            column_width: board.column_width,
+            row_height: board.row_height,
+            grid: board.to_grid_str().lines().map(String::from).collect(),
+            teams: board.get_custom_teams(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BoardRepr> for Board {
+    type Error = Connect4Error;
+
+    fn try_from(repr: BoardRepr) -> Result<Self, Self::Error> {
+        let parsed = Board::parse_grid(&repr.grid.join("\n"))?;
+        if parsed.num_rows != repr.num_rows || parsed.num_columns != repr.num_columns {
+            return Err(Connect4Error::Parse(format!(
+                "The grid is {}x{} but the board is {}x{}.",
+                parsed.num_rows, parsed.num_columns, repr.num_rows, repr.num_columns
+            )));
+        }
+        if repr.row_height == 0 {
+            return Err(Connect4Error::Parse(
+                "The row height must be at least 1 line.".into(),
+            ));
+        }
+        let mut board = Board {
+            column_width: repr.column_width,
+            row_height: repr.row_height,
+            ..parsed
+        };
+        for (color, team) in repr.teams {
+            team.validate()
+                .map_err(|e| Connect4Error::Parse(e.to_string()))?;
+            board.set_team(color, team);
+        }
+        Ok(board)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Board {
+    /// Returns a `Result` type with an `Ok` containing the board as JSON or an `Err` with a
+    /// `Connect4Error::InvalidState` if the board cannot be serialized.
+    ///
+    /// The cells are stored as the rows of `Board::to_grid_str`, so the JSON stays readable.
+    pub fn to_json(&self) -> Result<String, Connect4Error> {
+        serde_json::to_string_pretty(self).map_err(|e| {
+            Connect4Error::InvalidState(format!("Could not write the board as JSON: {e}"))
+        })
+    }
+
+    /// Parses a board from the JSON produced by `Board::to_json`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Board` instance or an `Err` with a
+    /// `Connect4Error::Parse` describing why `json` is not a valid board.
+    pub fn from_json(json: &str) -> Result<Self, Connect4Error> {
+        serde_json::from_str(json)
+            .map_err(|e| Connect4Error::Parse(format!("Invalid board JSON: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Returns a copy of `board` without its `BitBoard` mirror, so its winner is found by
+    /// scanning the lines of `data`.
+    fn without_bits(board: &Board) -> Board {
+        Board {
+            data: board.data.clone(),
+            num_rows: board.num_rows,
+            num_columns: board.num_columns,
+            column_width: board.column_width,
+            row_height: board.row_height,
+            bits: None,
+            teams: board.teams.clone(),
+        }
+    }
+
+    #[test]
+    fn bitboard_and_vector_winners_agree() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for (num_rows, num_columns) in [(6, 7), (4, 5), (7, 9), (3, 12), (10, 10)] {
+            for amount_to_win in 3..=5 {
+                for _ in 0..20 {
+                    let mut board = Board::new(num_rows, num_columns, 3, 7);
+                    let mut turn = 0;
+                    while !board.is_full() {
+                        let col_index = rng.gen_range(0..num_columns);
+                        if !board.available_column(col_index) {
+                            continue;
+                        }
+                        let color = [Color::RED, Color::BLACK][turn % 2];
+                        board.drop_piece(color, col_index).unwrap();
+                        turn += 1;
+                        let winner = board.get_winning_color(amount_to_win);
+                        assert_eq!(
+                            winner,
+                            without_bits(&board).get_winning_color(amount_to_win)
+                        );
+                        if winner.is_some() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn winners_are_found_in_every_direction() {
+        // horizontal, vertical, rising and falling diagonals
+        for moves in ["6672435", "6167616", "65543656416", "2112273144143"] {
+            let board = Board::from_moves(moves, 6, 7).unwrap();
+            assert_eq!(board.get_winning_color(4), Some(Color::RED), "{moves}");
+            assert_eq!(
+                without_bits(&board).get_winning_color(4),
+                Some(Color::RED),
+                "{moves}"
+            );
+        }
+    }
+
+    #[test]
+    fn oversized_boards_have_no_bitboard() {
+        assert!(Board::new(6, 7, 3, 7).bitboard().is_some());
+        assert!(Board::new(20, 20, 3, 7).bitboard().is_none());
+    }
+
+    #[test]
+    fn undo_restores_the_cells() {
+        let mut board = Board::new(4, 5, 3, 7);
+        board.drop_piece(Color::RED, 2).unwrap();
+        board.drop_piece(Color::BLACK, 2).unwrap();
+        assert_eq!(board.undo_piece(2).unwrap(), Color::BLACK);
+        assert_eq!(board.data[3][2], Some(Color::RED));
+        assert_eq!(board.data[2][2], None);
+        assert_eq!(board.bitboard().unwrap().get_height(2), 1);
+        assert_eq!(board.undo_piece(2).unwrap(), Color::RED);
+        assert!(matches!(
+            board.undo_piece(2),
+            Err(Connect4Error::ColumnEmpty { col_index: 2 })
+        ));
+        assert!(matches!(
+            board.undo_piece(5),
+            Err(Connect4Error::ColumnOutOfRange { col_index: 5, .. })
+        ));
+        assert_eq!(board, Board::new(4, 5, 3, 7));
+    }
+
+    #[test]
+    fn winning_lines_list_every_run_once() {
+        let board = Board::from_moves("6672435", 6, 7).unwrap();
+        assert_eq!(
+            board.get_winning_lines(4),
+            [vec![(5, 3), (5, 4), (5, 5), (5, 6)]]
+        );
+        // a run of five red pieces along the bottom and four black ones in the first column
+        let board = Board::from_moves("112131415", 6, 7).unwrap();
+        let black_run = vec![(1, 0), (2, 0), (3, 0), (4, 0)];
+        let red_run = vec![(5, 0), (5, 1), (5, 2), (5, 3), (5, 4)];
+        assert_eq!(
+            board.get_winning_lines(4),
+            [black_run.clone(), red_run.clone()]
+        );
+        assert_eq!(board.get_winning_lines(5), [red_run]);
+        assert_eq!(board.get_winning_line(4), Some(black_run));
+        assert!(board.get_winning_lines(6).is_empty());
+        assert_eq!(board.get_winning_line(6), None);
+    }
+
+    #[test]
+    fn winning_lines_agree_with_the_winner() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for amount_to_win in 3..=5 {
+            for _ in 0..50 {
+                let mut board = Board::new(6, 7, 3, 7);
+                let mut turn = 0;
+                while !board.is_full() && board.get_winning_color(amount_to_win).is_none() {
+                    let col_index = rng.gen_range(0..7);
+                    if board.available_column(col_index) {
+                        board
+                            .drop_piece([Color::RED, Color::BLACK][turn % 2], col_index)
+                            .unwrap();
+                        turn += 1;
+                    }
+                    let lines = board.get_winning_lines(amount_to_win);
+                    assert_eq!(
+                        board.get_winning_color(amount_to_win).is_some(),
+                        !lines.is_empty()
+                    );
+                    for line in lines {
+                        assert!(line.len() >= amount_to_win);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn boards_round_trip_through_moves_and_grids() {
+        for moves_str in ["4453", "1111", "", "1726354"] {
+            let board = Board::from_moves(moves_str, 6, 7).unwrap();
+            let grid = board.to_grid_str();
+            assert_eq!(Board::from_grid_str(&grid).unwrap(), board);
+            let moves = board.to_moves().unwrap();
+            assert_eq!(Board::from_moves(&moves, 6, 7).unwrap(), board);
+        }
+        assert_eq!(
+            Board::from_moves("4453", 6, 7).unwrap().to_grid_str(),
+            ".......\n.......\n.......\n.......\n...B...\n..BRR.."
+        );
+    }
+
+    #[test]
+    fn invalid_boards_are_rejected() {
+        assert!(Board::from_moves("1111111", 6, 7).is_err());
+        assert!(Board::from_moves("48", 6, 7).is_err());
+        assert!(Board::from_moves("11111", 4, 5).is_err());
+        assert!(Board::from_moves("6", 4, 5).is_err());
+        for (num_rows, num_columns) in [(0, 7), (6, 0), (0, 0)] {
+            assert!(matches!(
+                Board::from_moves("", num_rows, num_columns),
+                Err(Connect4Error::InvalidConfig(_))
+            ));
+        }
+        let board = Board::from_moves("1 12 12", 3, 12).unwrap();
+        assert_eq!((board.num_rows, board.num_columns), (3, 12));
+        assert_eq!(board.get_cell(1, 11), Some(Color::RED));
+        for grid in [
+            "", "...\n..", "...\n.X.", "R..\n...", "...\nRR.", "...\nBB.",
+        ] {
+            assert!(Board::from_grid_str(grid).is_err(), "{grid:?}");
+        }
+        let board = Board::from_grid_str("...\nbr.").unwrap();
+        assert_eq!(board.get_cell(1, 1), Some(Color::RED));
+        assert_eq!(board.get_cell(1, 0), Some(Color::BLACK));
+    }
+
+    #[test]
+    fn grids_can_hold_any_number_of_teams() {
+        for grid in ["....\nRBY.", "RB..\nRBY.", "R...\nRBYB"] {
+            assert!(Board::from_grid_str(grid).is_ok(), "{grid:?}");
+        }
+        for grid in ["....\nRBYY", "....\nR.Y.", "....\nRRRB"] {
+            assert!(Board::from_grid_str(grid).is_err(), "{grid:?}");
+        }
+        let board = Board::from_grid_str("....\nRBYG").unwrap();
+        assert_eq!(board.get_cell(1, 3), Some(Color::GREEN));
+        assert_eq!(board.to_grid_str(), "....\nRBYG");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trips() {
+        for moves_str in ["", "4453", "112131415"] {
+            let board = Board::from_moves(moves_str, 6, 7).unwrap();
+            let json = board.to_json().unwrap();
+            assert!(json.contains("\"grid\""));
+            let restored = Board::from_json(&json).unwrap();
+            assert_eq!(restored, board);
+            assert_eq!(restored.bitboard(), board.bitboard());
+        }
+        let mut board = Board::from_moves("4453", 6, 7).unwrap();
+        board.set_team(Color::BLACK, Team::new("Ravens".into(), 'V', (0, 0, 0)));
+        let restored = Board::from_json(&board.to_json().unwrap()).unwrap();
+        assert_eq!(restored.get_team(Color::BLACK).name, "Ravens");
+        assert_eq!(restored, board);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn invalid_json_is_rejected() {
+        let json = Board::from_moves("4453", 6, 7).unwrap().to_json().unwrap();
+        for invalid in [
+            "{}".to_string(),
+            "not json".to_string(),
+            json.replace("\"num_rows\": 6", "\"num_rows\": 5"),
+            json.replace("...B...", "......B"),
+            json.replace("\"row_height\": 3", "\"row_height\": 0"),
+        ] {
+            assert!(
+                matches!(Board::from_json(&invalid), Err(Connect4Error::Parse(_))),
+                "{invalid}"
+            );
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::Connect4Error;
+
+/// Returns the move-list notation of the column indices in `moves` for a board with
+/// `num_columns` columns.
+///
+/// Moves are written as 1-based column numbers in the order they were played. On boards with
+/// at most 9 columns the numbers are written back to back (`"4453"`); on wider boards they are
+/// separated by spaces (`"4 10 4 12"`).
+pub fn format_moves(moves: &[usize], num_columns: usize) -> String {
+    let separator = if num_columns <= 9 { "" } else { " " };
+    moves
+        .iter()
+        .map(|col_index| (col_index + 1).to_string())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+/// Parses the move-list notation `moves_str` for a board with `num_columns` columns into the
+/// column indices of the moves.
+///
+/// Both the compact and the space separated form are accepted regardless of the board width,
+/// as are commas in place of spaces.
+///
+/// Returns a `Result` type with an `Ok` containing the column indices or an `Err` with a
+/// `Connect4Error::Parse` naming the first invalid move.
+pub fn parse_moves(moves_str: &str, num_columns: usize) -> Result<Vec<usize>, Connect4Error> {
+    let moves_str = moves_str.trim();
+    let tokens: Vec<String> = if moves_str.contains(|c: char| c.is_whitespace() || c == ',') {
+        moves_str
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .map(String::from)
+            .collect()
+    } else {
+        moves_str.chars().map(String::from).collect()
+    };
+    tokens
+        .iter()
+        .enumerate()
+        .map(|(i, token)| match token.parse::<usize>() {
+            Ok(column) if column >= 1 && column <= num_columns => Ok(column - 1),
+            _ => Err(Connect4Error::Parse(format!(
+                "Move {} (\"{}\") is not a column number between 1 and {}.",
+                i + 1,
+                token,
+                num_columns
+            ))),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moves_round_trip() {
+        let moves = vec![3, 3, 4, 2, 0, 6];
+        assert_eq!(format_moves(&moves, 7), "445317");
+        assert_eq!(parse_moves("445317", 7).unwrap(), moves);
+        let wide = vec![3, 9, 3, 11, 0];
+        assert_eq!(format_moves(&wide, 12), "4 10 4 12 1");
+        assert_eq!(parse_moves("4 10 4 12 1", 12).unwrap(), wide);
+        assert_eq!(format_moves(&[], 7), "");
+        assert_eq!(parse_moves("  ", 7).unwrap(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn separators_are_accepted_on_any_board() {
+        assert_eq!(parse_moves("4 4,5", 7).unwrap(), vec![3, 3, 4]);
+        assert_eq!(parse_moves(" 4, 4, 5 ", 7).unwrap(), vec![3, 3, 4]);
+        assert_eq!(parse_moves("445", 12).unwrap(), vec![3, 3, 4]);
+    }
+
+    #[test]
+    fn invalid_moves_are_rejected() {
+        for (moves_str, num_columns, message) in [
+            (
+                "4480",
+                7,
+                "Move 3 (\"8\") is not a column number between 1 and 7.",
+            ),
+            (
+                "44x",
+                7,
+                "Move 3 (\"x\") is not a column number between 1 and 7.",
+            ),
+            (
+                "4 13",
+                12,
+                "Move 2 (\"13\") is not a column number between 1 and 12.",
+            ),
+        ] {
+            match parse_moves(moves_str, num_columns) {
+                Err(Connect4Error::Parse(error)) => assert_eq!(error, message),
+                result => panic!("{moves_str}: expected a parse error, got {result:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{BitBoard, Board, Color, Connect4Error};
+
+/// The operations search-based players need from a board representation.
+///
+/// Implemented for both `Board` and `BitBoard` so that engines can search on the fast `BitBoard`
+/// whenever the board fits in one and fall back to the `Board` otherwise.
+pub trait Position: Clone {
+    /// Returns the number of rows in the board.
+    fn num_rows(&self) -> usize;
+
+    /// Returns the number of columns in the board.
+    fn num_columns(&self) -> usize;
+
+    /// Returns the `Color` of the piece in the cell at `row_index` (counted from the top) and
+    /// `col_index`, or `None` if the cell is empty.
+    fn get_cell(&self, row_index: usize, col_index: usize) -> Option<Color>;
+
+    /// Returns `true` if the column with index `col_index` is available else `false`.
+    fn available_column(&self, col_index: usize) -> bool;
+
+    /// Drops a game piece of `Color` `color` in the column with index `col_index` and returns the
+    /// row index it landed in.
+    fn drop_piece(&mut self, color: Color, col_index: usize) -> Result<usize, Connect4Error>;
+
+    /// Removes the highest game piece from the column with index `col_index` and returns its
+    /// `Color`.
+    fn undo_piece(&mut self, col_index: usize) -> Result<Color, Connect4Error>;
+
+    /// Returns a `bool` indicating whether the entire board is full of game pieces.
+    fn is_full(&self) -> bool;
+
+    /// Returns `true` if the piece at `row_index` and `col_index` is part of a run of at least
+    /// `amount_to_win` pieces of its `Color` in any direction.
+    ///
+    /// Only the lines through the given cell are inspected, which makes this much cheaper than
+    /// `get_winning_color` for checking whether the last dropped piece won the game.
+    fn is_winning_cell(&self, row_index: usize, col_index: usize, amount_to_win: usize) -> bool {
+        let Some(color) = self.get_cell(row_index, col_index) else {
+            return false;
+        };
+        let count_towards = |row_step: isize, col_step: isize| -> usize {
+            let mut count = 0;
+            let mut row = row_index as isize + row_step;
+            let mut col = col_index as isize + col_step;
+            while row >= 0
+                && col >= 0
+                && (row as usize) < self.num_rows()
+                && (col as usize) < self.num_columns()
+                && self.get_cell(row as usize, col as usize) == Some(color)
+            {
+                count += 1;
+                row += row_step;
+                col += col_step;
+            }
+            count
+        };
+        [(0, 1), (1, 0), (1, 1), (1, -1)]
+            .into_iter()
+            .any(|(row_step, col_step)| {
+                1 + count_towards(row_step, col_step) + count_towards(-row_step, -col_step)
+                    >= amount_to_win
+            })
+    }
+}
+
+impl Position for Board {
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    fn get_cell(&self, row_index: usize, col_index: usize) -> Option<Color> {
+        Board::get_cell(self, row_index, col_index)
+    }
+
+    fn available_column(&self, col_index: usize) -> bool {
+        Board::available_column(self, col_index)
+    }
+
+    fn drop_piece(&mut self, color: Color, col_index: usize) -> Result<usize, Connect4Error> {
+        Board::drop_piece(self, color, col_index)
+    }
+
+    fn undo_piece(&mut self, col_index: usize) -> Result<Color, Connect4Error> {
+        Board::undo_piece(self, col_index)
+    }
+
+    fn is_full(&self) -> bool {
+        Board::is_full(self)
+    }
+}
+
+impl Position for BitBoard {
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    fn get_cell(&self, row_index: usize, col_index: usize) -> Option<Color> {
+        BitBoard::get_cell(self, row_index, col_index)
+    }
+
+    fn available_column(&self, col_index: usize) -> bool {
+        BitBoard::available_column(self, col_index)
+    }
+
+    fn drop_piece(&mut self, color: Color, col_index: usize) -> Result<usize, Connect4Error> {
+        BitBoard::drop_piece(self, color, col_index)
+    }
+
+    fn undo_piece(&mut self, col_index: usize) -> Result<Color, Connect4Error> {
+        BitBoard::undo_piece(self, col_index)
+    }
+
+    fn is_full(&self) -> bool {
+        BitBoard::is_full(self)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{Color, Connect4Error};
+
+/// How the pieces and players of a `Color` team are presented: the team's name, the glyph its
+/// pieces are drawn with when colors are unavailable and the RGB value they are drawn in
+/// otherwise.
+///
+/// Every color is presented with its built-in name, symbol and RGB value (see
+/// `Team::default_for`) unless a game configures a custom `Team` for it with
+/// `GameBuilder::team` or `Board::set_team`.
+///
+/// # Examples
+///
+/// ```
+/// use connect_4::{Color, Team};
+///
+/// let team: Team = "Dragons,D,#ff6600".parse().unwrap();
+/// assert_eq!(team, Team::new("Dragons".into(), 'D', (255, 102, 0)));
+/// assert_eq!(Team::default_for(Color::RED).to_string(), "Red,R,#dc2828");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Team {
+    /// The name shown for the team, e.g. in win messages and when pieces are drawn as words.
+    pub name: String,
+    /// The single character the team's pieces are drawn with when colors are unavailable.
+    pub glyph: char,
+    /// The RGB value the team's pieces are drawn in.
+    pub rgb: (u8, u8, u8),
+}
+
+impl Team {
+    /// Constructs a `Team` presented as `name`, with pieces drawn as `glyph` or in the RGB value
+    /// `rgb`.
+    pub fn new(name: String, glyph: char, rgb: (u8, u8, u8)) -> Self {
+        Team { name, glyph, rgb }
+    }
+
+    /// Returns the built-in presentation of `color`: its name, symbol and RGB value.
+    pub fn default_for(color: Color) -> Self {
+        Team::new(color.name().into(), color.symbol(), color.rgb())
+    }
+
+    /// Returns a `Result` type with a unit `Ok` if the team can be drawn and recorded or an `Err`
+    /// with a `Connect4Error::InvalidConfig` if the name is empty or contains a comma or a line
+    /// break, or the glyph is not a single visible character other than `.` and `,`.
+    pub fn validate(&self) -> Result<(), Connect4Error> {
+        if self.name.trim().is_empty() {
+            return Err(Connect4Error::InvalidConfig(
+                "Team names must not be empty.".into(),
+            ));
+        }
+        if self.name.contains(|c: char| c == ',' || c.is_control()) {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "The team name {:?} must not contain commas or line breaks.",
+                self.name
+            )));
+        }
+        if self.glyph.is_whitespace() || self.glyph.is_control() || matches!(self.glyph, '.' | ',')
+        {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "The glyph {:?} of team {} is not a visible character.",
+                self.glyph, self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Formats the team as `name,glyph,#rrggbb`, which `Team::from_str` parses back.
+impl std::fmt::Display for Team {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (r, g, b) = self.rgb;
+        write!(
+            f,
+            "{},{},#{:02x}{:02x}{:02x}",
+            self.name, self.glyph, r, g, b
+        )
+    }
+}
+
+/// Parses a team from the `name,glyph,#rrggbb` form produced by `Display`.
+impl std::str::FromStr for Team {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            Connect4Error::Parse(format!(
+                "Expected a team as name,glyph,#rrggbb but got \"{}\".",
+                s.trim()
+            ))
+        };
+        let parts = s.split(',').map(str::trim).collect::<Vec<&str>>();
+        let [name, glyph, hex] = parts[..] else {
+            return Err(invalid());
+        };
+        let mut glyph_chars = glyph.chars();
+        let glyph = match (glyph_chars.next(), glyph_chars.next()) {
+            (Some(glyph), None) => glyph,
+            _ => return Err(invalid()),
+        };
+        let digits = hex.strip_prefix('#').ok_or_else(invalid)?;
+        let channel = |index: usize| {
+            digits
+                .get(2 * index..2 * index + 2)
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(invalid)
+        };
+        if digits.len() != 6 {
+            return Err(invalid());
+        }
+        let team = Team::new(name.into(), glyph, (channel(0)?, channel(1)?, channel(2)?));
+        team.validate()
+            .map_err(|e| Connect4Error::Parse(e.to_string()))?;
+        Ok(team)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn teams_round_trip_through_text() {
+        for color in Color::ALL {
+            let team = Team::default_for(color);
+            assert_eq!(team.to_string().parse::<Team>().unwrap(), team);
+        }
+        let team: Team = " Les Bleus , \u{2605} , #0055A4 ".parse().unwrap();
+        assert_eq!(
+            team,
+            Team::new("Les Bleus".into(), '\u{2605}', (0, 85, 164))
+        );
+        assert_eq!(team.to_string(), "Les Bleus,\u{2605},#0055a4");
+    }
+
+    #[test]
+    fn invalid_teams_are_rejected() {
+        for text in [
+            "Dragons",
+            "Dragons,D",
+            "Dragons,DD,#ff6600",
+            "Dragons,D,ff6600",
+            "Dragons,D,#ff66",
+            "Dragons,D,#gg6600",
+            "Dragons,D,#ff6600,extra",
+            ",D,#ff6600",
+            "Dragons,.,#ff6600",
+        ] {
+            assert!(
+                matches!(text.parse::<Team>(), Err(Connect4Error::Parse(_))),
+                "{text}"
+            );
+        }
+        assert!(Team::new("  ".into(), 'D', (0, 0, 0)).validate().is_err());
+        for (name, glyph) in [
+            ("Tabs", '\t'),
+            ("Commas", ','),
+            ("A, B", 'A'),
+            ("A\nB", 'A'),
+        ] {
+            let team = Team::new(name.into(), glyph, (0, 0, 0));
+            assert!(
+                matches!(team.validate(), Err(Connect4Error::InvalidConfig(_))),
+                "{team:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,429 @@
+use super::seat::SeatKind;
+use super::{MatchOptions, OutputFormat};
+use connect_4::simulation::Simulation;
+use connect_4::{
+    Action, Board, Color, Connect4Error, Game, GameBuilder, GameEvent, GameObserver, GameRecord,
+    PieceStyle, Player, Solver, TerminalObserver, TurnContext,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+/// Announces every move of the wrapped player, so that games between computer players can be
+/// followed.
+struct WatchedPlayer {
+    player: Box<dyn Player>,
+}
+
+impl WatchedPlayer {
+    /// Prints whose turn it is under the board drawn by the game.
+    fn show(&self, ctx: &TurnContext) -> Result<(), Connect4Error> {
+        println!(
+            "{} ({}) is thinking...",
+            ctx.board.get_team(ctx.color).name,
+            self.player.get_name()
+        );
+        Ok(())
+    }
+}
+
+impl Player for WatchedPlayer {
+    fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        self.show(ctx)?;
+        self.player.get_column_index(ctx)
+    }
+
+    fn get_action(&mut self, ctx: &TurnContext) -> Result<Action, Connect4Error> {
+        self.show(ctx)?;
+        self.player.get_action(ctx)
+    }
+
+    fn get_name(&self) -> String {
+        self.player.get_name()
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.player.set_seed(seed);
+    }
+}
+
+/// Pauses the game after every move, outside of the players' turns so that the pause is not
+/// charged to their clocks.
+struct Pause {
+    delay: Duration,
+}
+
+impl GameObserver for Pause {
+    fn on_event(&mut self, event: &GameEvent) -> Result<(), Connect4Error> {
+        if let GameEvent::PieceDropped { .. } = event {
+            std::thread::sleep(self.delay);
+        }
+        Ok(())
+    }
+}
+
+/// Constructs the player for `seat` with the given `name`, seating humans at the full-screen
+/// interface unless `plain` is set or standard input is not a terminal.
+#[cfg_attr(not(feature = "tui"), allow(unused_variables))]
+fn build_player(
+    options: &MatchOptions,
+    seat: &SeatKind,
+    name: String,
+    plain: bool,
+) -> Box<dyn Player> {
+    #[cfg(feature = "tui")]
+    if seat.is_human() && !plain && std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+        return Box::new(connect_4::TuiPlayer::with_style(
+            name,
+            options.piece_style(),
+        ));
+    }
+    seat.build(name)
+}
+
+/// Plays a match with the seats in `options`, showing every move and pausing for `delay` after
+/// it if `delay` is given.
+///
+/// Humans use the full-screen interface unless `plain` is set, and the game is written to
+/// `save_path` when they save it.
+pub fn play(
+    options: &MatchOptions,
+    delay: Option<Duration>,
+    plain: bool,
+    save_path: Option<&str>,
+) -> Result<(), Connect4Error> {
+    let players = options
+        .seats
+        .iter()
+        .zip(options.seat_names())
+        .map(|(seat, name)| {
+            let player = build_player(options, seat, name, plain);
+            match delay {
+                Some(_) => Box::new(WatchedPlayer { player }),
+                None => player,
+            }
+        })
+        .collect();
+    let mut builder = GameBuilder::new()
+        .board_size(options.num_rows, options.num_columns)
+        .amount_to_win(options.amount_to_win)
+        .players(players, options.seat_colors())
+        .starting_player(options.starting_player_index)
+        .observer(Box::new(TerminalObserver::with_style(
+            options.piece_style(),
+        )));
+    if let Some(delay) = delay {
+        builder = builder.observer(Box::new(Pause { delay }));
+    }
+    if let Some(time_control) = options.time_control {
+        builder = builder
+            .time_control(time_control)
+            .clock_scope(options.clock_scope);
+    }
+    if let Some(seed) = options.seed {
+        builder = builder.seed(seed);
+    }
+    for (color, team) in options.teams.iter() {
+        builder = builder.team(*color, team.clone());
+    }
+    if let Some(save_path) = save_path {
+        builder = builder.save_path(save_path);
+    }
+    if options.compact {
+        builder = builder.render_size(1, 3);
+    }
+    let mut game = builder.build()?;
+    game.start()?;
+    Ok(())
+}
+
+/// Continues the game saved at `path`, seating the players in `options` or humans in place of
+/// the saved players and keeping the saved names unless `options` renames them.
+///
+/// Humans use the full-screen interface unless `plain` is set, and the game is written back to
+/// `path` when they save it.
+pub fn resume(options: &MatchOptions, plain: bool, path: &str) -> Result<(), Connect4Error> {
+    let record = GameRecord::load(path)?;
+    let num_players = record.player_names.len();
+    if options.seats.len() > num_players || options.names.len() > num_players {
+        return Err(Connect4Error::Parse(format!(
+            "There are more --player or --name flags than saved players ({num_players})."
+        )));
+    }
+    let players = record
+        .player_names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let seat = options.seats.get(i).unwrap_or(&SeatKind::Human);
+            let name = options.names.get(i).unwrap_or(name).clone();
+            build_player(options, seat, name, plain)
+        })
+        .collect();
+    let mut game = Game::from_record(&record, players)?;
+    if game.get_outcome().is_some() {
+        return Err(Connect4Error::InvalidState(format!(
+            "The game saved in {path} has already ended."
+        )));
+    }
+    game.add_observer(Box::new(TerminalObserver::with_style(
+        options.piece_style(),
+    )));
+    game.set_save_path(Some(path.into()));
+    game.resume()?;
+    Ok(())
+}
+
+/// Prints the solver's evaluation of the position reached by `moves` on a 7x6 board and of
+/// every move from it.
+pub fn solve(moves: &str) -> Result<(), Connect4Error> {
+    let board = Board::from_moves(moves, 6, 7)?;
+    let num_moves = board.get_data().iter().flatten().flatten().count();
+    let to_move = if num_moves % 2 == 0 {
+        Color::RED
+    } else {
+        Color::BLACK
+    };
+    board.print_styled(PieceStyle::detect(), &[])?;
+    if num_moves < 8 {
+        println!("Solving a position this early can take several minutes...");
+    }
+    let scores = Solver::new().analyze(&board, to_move)?;
+    let best = scores.iter().flatten().max_by_key(|score| score.rank());
+    match best {
+        Some(score) => println!("{to_move} to move: {score}"),
+        None => println!("The board is full."),
+    }
+    for (col_index, score) in scores.iter().enumerate() {
+        match score {
+            Some(score) => println!("  column {}: {score}", col_index + 1),
+            None => println!("  column {}: full", col_index + 1),
+        }
+    }
+    Ok(())
+}
+
+/// Returns `count` undecided positions on boards of the size in `options`, each reached by
+/// `plies` random moves of alternating Red and Black pieces.
+fn random_positions(
+    options: &MatchOptions,
+    count: usize,
+    plies: usize,
+    rng: &mut StdRng,
+) -> Result<Vec<Board>, Connect4Error> {
+    const MAX_ATTEMPTS: usize = 1000;
+    let mut positions = Vec::new();
+    let mut attempts = 0;
+    while positions.len() < count {
+        attempts += 1;
+        if attempts > MAX_ATTEMPTS * count {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "Could not find undecided positions after {plies} random moves."
+            )));
+        }
+        let mut board = Board::new(options.num_rows, options.num_columns, 3, 7);
+        for ply in 0..plies {
+            if board.is_full() || board.get_winning_color(options.amount_to_win).is_some() {
+                break;
+            }
+            let available = (0..board.num_columns)
+                .filter(|col_index| board.available_column(*col_index))
+                .collect::<Vec<usize>>();
+            let color = if ply % 2 == 0 {
+                Color::RED
+            } else {
+                Color::BLACK
+            };
+            board.drop_piece(color, available[rng.gen_range(0..available.len())])?;
+        }
+        if !board.is_full() && board.get_winning_color(options.amount_to_win).is_none() {
+            positions.push(board);
+        }
+    }
+    Ok(positions)
+}
+
+/// Times every seat in `options` on the same `positions` random positions, each reached by
+/// `plies` random moves, and prints the average and slowest time per move.
+pub fn bench(options: &MatchOptions, positions: usize, plies: usize) -> Result<(), Connect4Error> {
+    if positions == 0 {
+        return Err(Connect4Error::InvalidConfig(
+            "There must be at least one position.".into(),
+        ));
+    }
+    let seed = options.seed.unwrap_or_else(|| rand::thread_rng().gen());
+    let boards = random_positions(options, positions, plies, &mut StdRng::seed_from_u64(seed))?;
+    let player_colors = [Color::RED, Color::BLACK];
+    let player_names = ["Red".to_string(), "Black".to_string()];
+    println!(
+        "Timing {} position(s) on a {}x{} board after {} random move(s) (seed {}).",
+        positions, options.num_rows, options.num_columns, plies, seed
+    );
+    println!("{:<16} {:>12} {:>12}", "player", "avg/move", "max/move");
+    for seat in options.seats.iter() {
+        let mut player = seat.build(seat.to_string());
+        player.set_seed(seed);
+        let mut total = Duration::ZERO;
+        let mut slowest = Duration::ZERO;
+        for board in boards.iter() {
+            let num_moves = board.get_data().iter().flatten().flatten().count();
+            let ctx = TurnContext {
+                board,
+                color: player_colors[num_moves % 2],
+                amount_to_win: options.amount_to_win,
+                player_colors: &player_colors,
+                player_names: &player_names,
+                player_index: num_moves % 2,
+                time_left: None,
+                clock: None,
+                draw_offer: None,
+                draw_declined_by: None,
+            };
+            let start = Instant::now();
+            player.get_column_index(&ctx)?;
+            let elapsed = start.elapsed();
+            total += elapsed;
+            slowest = slowest.max(elapsed);
+        }
+        println!(
+            "{:<16} {:>9.2} ms {:>9.2} ms",
+            seat.to_string(),
+            total.as_secs_f64() * 1000.0 / positions as f64,
+            slowest.as_secs_f64() * 1000.0
+        );
+    }
+    Ok(())
+}
+
+/// Writes `contents` to the file at `path`.
+fn write_file(path: &str, contents: &str) -> Result<(), Connect4Error> {
+    std::fs::write(path, contents).map_err(|e| {
+        Connect4Error::Io(std::io::Error::new(
+            e.kind(),
+            format!("Could not write {path}: {e}"),
+        ))
+    })
+}
+
+/// Plays `games` headless games between the seats in `options` and prints or writes a summary
+/// in `format`, along with a CSV log of every game if `log` is given.
+///
+/// The first move rotates through the seats unless `fixed_start` is set, in which case the
+/// starting seat of `options` always moves first.
+pub fn simulate(
+    options: &MatchOptions,
+    fixed_start: bool,
+    games: usize,
+    format: OutputFormat,
+    output: Option<&str>,
+    log: Option<&str>,
+) -> Result<(), Connect4Error> {
+    if format == OutputFormat::Json && !cfg!(feature = "serde") {
+        return Err(Connect4Error::InvalidConfig(
+            "JSON output needs connect_4 to be built with the \"serde\" feature.".into(),
+        ));
+    }
+    let mut simulation = Simulation::new()
+        .board_size(options.num_rows, options.num_columns)
+        .amount_to_win(options.amount_to_win)
+        .games(games)
+        .starting_player(fixed_start.then_some(options.starting_player_index));
+    if let Some(seed) = options.seed {
+        simulation = simulation.seed(seed);
+    }
+    if let Some(time_control) = options.time_control {
+        simulation = simulation
+            .time_control(time_control)
+            .clock_scope(options.clock_scope);
+    }
+    for ((seat, name), color) in options
+        .seats
+        .iter()
+        .zip(options.seat_names())
+        .zip(options.seat_colors())
+    {
+        let seat = *seat;
+        simulation = simulation.seat(color, move || seat.build(name.clone()));
+    }
+    let report = simulation.run()?;
+    let summary = match format {
+        OutputFormat::Text => report.to_string(),
+        OutputFormat::Csv => report.to_csv(),
+        #[cfg(feature = "serde")]
+        OutputFormat::Json => report.to_json()?,
+        #[cfg(not(feature = "serde"))]
+        OutputFormat::Json => unreachable!("JSON output is rejected before playing"),
+    };
+    match output {
+        Some(path) => write_file(path, &summary)?,
+        None => println!("{}", summary.trim_end()),
+    }
+    if let Some(path) = log {
+        write_file(path, &report.games_csv())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{parse, Command};
+
+    /// Returns the match options of `bench` with the given arguments.
+    fn bench_options(args: &str) -> MatchOptions {
+        let args = format!("bench {args}")
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+        match parse(args).unwrap() {
+            Command::Bench { options, .. } => options,
+            command => panic!("expected a bench command, got {command:?}"),
+        }
+    }
+
+    #[test]
+    fn random_positions_are_undecided() {
+        let options = bench_options("--rows 4 --columns 5 --win 3");
+        let mut rng = StdRng::seed_from_u64(3);
+        let boards = random_positions(&options, 20, 6, &mut rng).unwrap();
+        assert_eq!(boards.len(), 20);
+        for board in boards {
+            assert_eq!((board.num_rows, board.num_columns), (4, 5));
+            assert!(!board.is_full());
+            assert_eq!(board.get_winning_color(3), None);
+            assert!(board.get_data().iter().flatten().flatten().count() <= 6);
+        }
+    }
+
+    #[test]
+    fn impossible_positions_are_reported() {
+        let options = bench_options("--win 1");
+        let mut rng = StdRng::seed_from_u64(3);
+        assert!(matches!(
+            random_positions(&options, 1, 1, &mut rng),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            bench(&options, 0, 8),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn ended_or_missing_games_cannot_be_resumed() {
+        let options = bench_options("");
+        let path = std::env::temp_dir().join(format!("connect_4_resume_{}.c4", std::process::id()));
+        let path = path.to_str().unwrap();
+        assert!(matches!(
+            resume(&options, true, path),
+            Err(Connect4Error::Io(_))
+        ));
+        let text = "rows: 6\ncolumns: 7\nplayer: Red A\nplayer: Black B\nresult: resign 1\n";
+        std::fs::write(path, text).unwrap();
+        let ended = resume(&options, true, path);
+        let crowded = resume(&bench_options("-p easy -p easy -p easy"), true, path);
+        std::fs::remove_file(path).unwrap();
+        assert!(matches!(ended, Err(Connect4Error::InvalidState(_))));
+        assert!(matches!(crowded, Err(Connect4Error::Parse(_))));
+    }
+}
@@ -0,0 +1,802 @@
+mod commands;
+mod seat;
+
+use connect_4::{
+    ClockScope, Color, ColorMode, Connect4Error, Difficulty, PieceStyle, Team, TimeControl,
+};
+use seat::SeatKind;
+use std::time::Duration;
+
+/// The help text printed by `connect_4 help`.
+const USAGE: &str = "\
+Usage: connect_4 [COMMAND] [OPTIONS]
+
+Commands:
+  play            Play a game in the terminal (the default)
+  resume <FILE>   Continue a game saved with play's save key
+  watch           Watch computer players play each other
+  solve [MOVES]   Solve a 7x6 position given as 1-based columns, e.g. 4453
+  bench           Time how long computer players take to move
+  simulate        Play many games between computer players and print statistics
+  help            Print this help
+
+Match options (play, watch, bench and simulate):
+  -r, --rows <N>          Number of board rows [default: 6]
+  -c, --columns <N>       Number of board columns [default: 7]
+  -w, --win <N>           Number of pieces in a row needed to win [default: 4]
+  -p, --player <TYPE>     Add a seat: human, random, easy, medium, hard, perfect,
+                          minimax:<depth> or mcts:<iterations> (repeatable)
+  -n, --name <NAME>       Name the next seat in order (repeatable)
+      --color <COLOR>     Set the color of the next seat in order: red, black, yellow,
+                          green, cyan, purple, orange, white, magenta, teal, lime,
+                          navy, silver, amber, violet or indigo (repeatable)
+                          [default: cycling through the first --teams colors]
+      --teams <N>         Number of teams the seats without --color cycle through,
+                          at most 16 [default: 2]
+  -s, --start <SEAT>      1-based seat which moves first [default: 1, or rotating
+                          for simulate]
+  -d, --difficulty <LVL>  Difficulty of the default bots when no --player is given
+      --time <CONTROL>    Time the game in seconds: sudden:<initial>,
+                          fischer:<initial>+<increment> or move:<limit>; running out
+                          of time loses the game [default: untimed]
+      --clock <SCOPE>     Give every player or every team a clock: player or team
+                          [default: player]
+      --seed <N>          Seed for reproducing the computer players' moves, and the
+                          positions of bench or the games of simulate [default: random]
+
+Display options (play, watch and resume):
+      --team <COLOR>=<NAME>[,<GLYPH>[,<#RRGGBB>]]
+                          Rename a team and optionally change the glyph its pieces
+                          are drawn with without colors and their color, e.g.
+                          red=Dragons,D,#ff6600 (repeatable)
+      --colors <MODE>     Disc colors: auto, none, 16, 256 or truecolor [default: auto,
+                          which honors NO_COLOR]
+      --words             Draw pieces as color names instead of discs
+      --compact           Draw one line per row and three characters per column
+
+Play options:
+      --plain             Type column numbers instead of using the full-screen interface
+                          (also for resume)
+      --save <PATH>       File the game is written to with the save key [default: game.c4]
+
+Resume options:
+  -p, --player <TYPE>     Seat a player type in place of the next saved player in order
+                          [default: human]
+  -n, --name <NAME>       Rename the next saved player in order
+
+Watch options:
+      --delay <MS>        Pause after each move in milliseconds [default: 500]
+
+Bench options:
+      --positions <N>     Number of positions to time each player on [default: 20]
+      --plies <N>         Random moves played to reach each position [default: 8]
+
+Simulate options:
+      --games <N>         Number of games to play [default: 100]
+      --format <FORMAT>   Summary format: text, csv or json [default: text]
+  -o, --output <PATH>     Write the summary to a file instead of the terminal
+      --log <PATH>        Write a CSV log of every game to a file
+
+Without --player, play seats a human and three bots, watch and simulate seat two medium
+bots, bench times a medium bot and minimax:6 and resume seats humans. resume saves the
+game back to the file it was loaded from.";
+
+/// A parsed command line.
+#[derive(Debug)]
+pub enum Command {
+    Play {
+        options: MatchOptions,
+        /// Whether humans type column numbers instead of using the full-screen interface.
+        plain: bool,
+        save_path: String,
+    },
+    Watch {
+        options: MatchOptions,
+        delay: Duration,
+    },
+    /// Continues the game saved at `path`, with the seats and names in `options` replacing
+    /// the saved players'.
+    Resume {
+        options: MatchOptions,
+        plain: bool,
+        path: String,
+    },
+    Solve {
+        moves: String,
+    },
+    Bench {
+        options: MatchOptions,
+        positions: usize,
+        plies: usize,
+    },
+    Simulate {
+        options: MatchOptions,
+        /// Whether `--start` was given; otherwise the first move rotates through the seats.
+        fixed_start: bool,
+        games: usize,
+        format: OutputFormat,
+        output: Option<String>,
+        log: Option<String>,
+    },
+    Help,
+}
+
+/// The format of the summary printed by `simulate`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OutputFormat {
+    Text,
+    Csv,
+    Json,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(Connect4Error::Parse(format!(
+                "Unknown format \"{s}\" (expected text, csv or json)."
+            ))),
+        }
+    }
+}
+
+/// The board and seating of a match.
+#[derive(Debug)]
+pub struct MatchOptions {
+    pub num_rows: usize,
+    pub num_columns: usize,
+    pub amount_to_win: usize,
+    pub seats: Vec<SeatKind>,
+    pub names: Vec<String>,
+    pub colors: Vec<Color>,
+    /// The number of teams the seats without a `--color` cycle through (`--teams`).
+    pub num_teams: usize,
+    /// The index of the seat which moves first.
+    pub starting_player_index: usize,
+    /// The seed given with `--seed`, if any.
+    pub seed: Option<u64>,
+    /// The time control given with `--time`, if any.
+    pub time_control: Option<TimeControl>,
+    /// Whether every player or every team has a clock (`--clock`).
+    pub clock_scope: ClockScope,
+    /// The colors given with `--colors`, or `None` to detect them.
+    pub color_mode: Option<ColorMode>,
+    /// Whether pieces are drawn as color names (`--words`).
+    pub words: bool,
+    /// Whether the board is drawn compactly (`--compact`).
+    pub compact: bool,
+    /// The custom team presentations given with `--team`.
+    pub teams: Vec<(Color, Team)>,
+}
+
+impl MatchOptions {
+    /// Returns the name of every seat, using the names given with `--name` and numbering the
+    /// remaining humans as "Player N" and computer players as "Bot N".
+    pub fn seat_names(&self) -> Vec<String> {
+        let (mut humans, mut bots) = (0, 0);
+        self.seats
+            .iter()
+            .enumerate()
+            .map(|(i, seat)| {
+                let default = if seat.is_human() {
+                    humans += 1;
+                    format!("Player {humans}")
+                } else {
+                    bots += 1;
+                    format!("Bot {bots}")
+                };
+                self.names.get(i).cloned().unwrap_or(default)
+            })
+            .collect()
+    }
+
+    /// Returns the `PieceStyle` given with `--words` and `--colors`.
+    pub fn piece_style(&self) -> PieceStyle {
+        if self.words {
+            PieceStyle::Words
+        } else {
+            PieceStyle::Discs(self.color_mode.unwrap_or_else(ColorMode::detect))
+        }
+    }
+
+    /// Returns the color of every seat, using the colors given with `--color` and cycling
+    /// through the first `num_teams` colors (see `Color::ALL`) for the remaining seats.
+    pub fn seat_colors(&self) -> Vec<Color> {
+        (0..self.seats.len())
+            .map(|i| {
+                self.colors
+                    .get(i)
+                    .copied()
+                    .unwrap_or(Color::ALL[i % self.num_teams])
+            })
+            .collect()
+    }
+}
+
+/// Walks the arguments of a command, splitting `--flag=value` into the flag and its value.
+struct Args {
+    args: std::vec::IntoIter<String>,
+    pending_value: Option<String>,
+}
+
+impl Args {
+    fn new(args: Vec<String>) -> Self {
+        Args {
+            args: args.into_iter(),
+            pending_value: None,
+        }
+    }
+
+    /// Returns the next flag or positional argument.
+    fn next(&mut self) -> Result<Option<String>, Connect4Error> {
+        if let Some(value) = self.pending_value.take() {
+            return Err(Connect4Error::Parse(format!(
+                "Unexpected value \"{value}\"."
+            )));
+        }
+        Ok(self.args.next().map(|arg| match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                self.pending_value = Some(value.to_string());
+                flag.to_string()
+            }
+            _ => arg,
+        }))
+    }
+
+    /// Returns the value of `flag`, which is either attached with `=` or the next argument.
+    fn value(&mut self, flag: &str) -> Result<String, Connect4Error> {
+        self.pending_value
+            .take()
+            .or_else(|| self.args.next())
+            .ok_or_else(|| Connect4Error::Parse(format!("Missing value for \"{flag}\".")))
+    }
+
+    /// Returns the value of `flag` parsed as a number.
+    fn number<T: std::str::FromStr>(&mut self, flag: &str) -> Result<T, Connect4Error> {
+        let value = self.value(flag)?;
+        value.parse().map_err(|_| {
+            Connect4Error::Parse(format!("Invalid number \"{value}\" for \"{flag}\"."))
+        })
+    }
+}
+
+/// Parses the value of `--team` in the form `COLOR=NAME[,GLYPH[,#RRGGBB]]`, taking the parts
+/// left out from the color's built-in presentation.
+fn parse_team(value: &str) -> Result<(Color, Team), Connect4Error> {
+    let (color, spec) = value.split_once('=').ok_or_else(|| {
+        Connect4Error::Parse(format!(
+            "Expected a team as COLOR=NAME[,GLYPH[,#RRGGBB]] but got \"{value}\"."
+        ))
+    })?;
+    let color: Color = color.parse()?;
+    let default = Team::default_for(color).to_string();
+    let defaults = default.split(',').skip(spec.split(',').count());
+    let spec = std::iter::once(spec)
+        .chain(defaults)
+        .collect::<Vec<&str>>()
+        .join(",");
+    Ok((color, spec.parse()?))
+}
+
+/// Parses the command line `args` (without the program name) into a `Command`.
+///
+/// Returns a `Result` type with an `Ok` containing the `Command` or an `Err` with a
+/// `Connect4Error` describing the first invalid argument.
+pub fn parse(args: Vec<String>) -> Result<Command, Connect4Error> {
+    let mut args = args;
+    let command = match args.first().map(String::as_str) {
+        Some("play" | "resume" | "watch" | "solve" | "bench" | "simulate" | "help") => {
+            args.remove(0)
+        }
+        Some("-h" | "--help") => return Ok(Command::Help),
+        _ => "play".to_string(),
+    };
+    let mut args = Args::new(args);
+    let mut options = MatchOptions {
+        num_rows: 6,
+        num_columns: 7,
+        amount_to_win: 4,
+        seats: Vec::new(),
+        names: Vec::new(),
+        colors: Vec::new(),
+        num_teams: 2,
+        starting_player_index: 0,
+        seed: None,
+        time_control: None,
+        clock_scope: ClockScope::Player,
+        color_mode: None,
+        words: false,
+        compact: false,
+        teams: Vec::new(),
+    };
+    let mut difficulty = None;
+    let mut delay = Duration::from_millis(500);
+    let (mut positions, mut plies) = (20, 8);
+    let mut moves = None;
+    let mut path = None;
+    let (mut fixed_start, mut games, mut format) = (false, 100, OutputFormat::Text);
+    let (mut output, mut log) = (None, None);
+    let (mut plain, mut save_path) = (false, "game.c4".to_string());
+    while let Some(arg) = args.next()? {
+        match (command.as_str(), arg.as_str()) {
+            (_, "-h" | "--help") => return Ok(Command::Help),
+            ("solve", _) if moves.is_none() && !arg.starts_with('-') => moves = Some(arg),
+            ("resume", _) if path.is_none() && !arg.starts_with('-') => path = Some(arg),
+            ("play" | "watch" | "bench" | "simulate", "-r" | "--rows") => {
+                options.num_rows = args.number(&arg)?
+            }
+            ("play" | "watch" | "bench" | "simulate", "-c" | "--columns") => {
+                options.num_columns = args.number(&arg)?
+            }
+            ("play" | "watch" | "bench" | "simulate", "-w" | "--win") => {
+                options.amount_to_win = args.number(&arg)?
+            }
+            ("play" | "resume" | "watch" | "bench" | "simulate", "-p" | "--player") => {
+                options.seats.push(args.value(&arg)?.parse()?)
+            }
+            ("play" | "resume" | "watch" | "simulate", "-n" | "--name") => {
+                options.names.push(args.value(&arg)?)
+            }
+            ("play" | "watch" | "simulate", "--color") => {
+                options.colors.push(args.value(&arg)?.parse()?)
+            }
+            ("play" | "watch" | "simulate", "--teams") => {
+                options.num_teams = args.number(&arg)?;
+                if options.num_teams < 2 {
+                    return Err(Connect4Error::Parse(
+                        "A game needs at least 2 teams.".into(),
+                    ));
+                }
+                Color::try_new(options.num_teams - 1)?;
+            }
+            ("play" | "watch" | "simulate", "--time") => {
+                options.time_control = Some(args.value(&arg)?.parse()?)
+            }
+            ("play" | "watch" | "simulate", "--clock") => {
+                options.clock_scope = args.value(&arg)?.parse()?
+            }
+            ("play" | "watch" | "simulate", "-s" | "--start") => {
+                let seat: usize = args.number(&arg)?;
+                options.starting_player_index = seat
+                    .checked_sub(1)
+                    .ok_or_else(|| Connect4Error::Parse("Seat numbers start at 1.".into()))?;
+                fixed_start = true;
+            }
+            ("play" | "watch", "-d" | "--difficulty") => {
+                difficulty = Some(args.value(&arg)?.parse::<Difficulty>()?)
+            }
+            ("play" | "resume" | "watch", "--colors") => {
+                options.color_mode = match args.value(&arg)?.to_lowercase().as_str() {
+                    "auto" => None,
+                    "none" => Some(ColorMode::NoColor),
+                    "16" => Some(ColorMode::Ansi16),
+                    "256" => Some(ColorMode::Ansi256),
+                    "truecolor" => Some(ColorMode::TrueColor),
+                    value => {
+                        return Err(Connect4Error::Parse(format!(
+                            "Unknown color mode \"{value}\" (expected auto, none, 16, 256 or \
+                             truecolor)."
+                        )))
+                    }
+                }
+            }
+            ("play" | "watch", "--team") => options.teams.push(parse_team(&args.value(&arg)?)?),
+            ("play" | "resume" | "watch", "--words") => options.words = true,
+            ("play" | "watch", "--compact") => options.compact = true,
+            ("play" | "resume", "--plain") => plain = true,
+            ("play", "--save") => save_path = args.value(&arg)?,
+            ("watch", "--delay") => delay = Duration::from_millis(args.number(&arg)?),
+            ("bench", "--positions") => positions = args.number(&arg)?,
+            ("bench", "--plies") => plies = args.number(&arg)?,
+            ("play" | "watch" | "bench" | "simulate", "--seed") => {
+                options.seed = Some(args.number(&arg)?)
+            }
+            ("simulate", "--games") => games = args.number(&arg)?,
+            ("simulate", "--format") => format = args.value(&arg)?.parse()?,
+            ("simulate", "-o" | "--output") => output = Some(args.value(&arg)?),
+            ("simulate", "--log") => log = Some(args.value(&arg)?),
+            _ => {
+                return Err(Connect4Error::Parse(format!(
+                    "Unexpected argument \"{arg}\" for \"{command}\" (see \"connect_4 help\")."
+                )))
+            }
+        }
+    }
+    if difficulty.is_some() && !options.seats.is_empty() {
+        return Err(Connect4Error::Parse(
+            "--difficulty only applies to the default bots; use --player instead.".into(),
+        ));
+    }
+    if command == "resume" && path.is_none() {
+        return Err(Connect4Error::Parse(
+            "\"resume\" needs the file of a saved game.".into(),
+        ));
+    }
+    if options.seats.is_empty() && command != "resume" {
+        let difficulty = difficulty.unwrap_or(Difficulty::Random);
+        options.seats = match command.as_str() {
+            "play" => vec![
+                SeatKind::Human,
+                SeatKind::Ai(difficulty),
+                SeatKind::Ai(difficulty),
+                SeatKind::Ai(difficulty),
+            ],
+            "watch" | "simulate" => vec![SeatKind::Ai(Difficulty::Medium); 2],
+            _ => vec![SeatKind::Ai(Difficulty::Medium), SeatKind::Minimax(6)],
+        };
+    }
+    if command != "resume"
+        && (options.names.len() > options.seats.len() || options.colors.len() > options.seats.len())
+    {
+        return Err(Connect4Error::Parse(format!(
+            "There are more --name or --color flags than players ({}).",
+            options.seats.len()
+        )));
+    }
+    if command != "play" && command != "resume" && options.seats.iter().any(SeatKind::is_human) {
+        return Err(Connect4Error::Parse(format!(
+            "Human players cannot take part in \"{command}\"."
+        )));
+    }
+    Ok(match command.as_str() {
+        "play" => Command::Play {
+            options,
+            plain,
+            save_path,
+        },
+        "resume" => Command::Resume {
+            options,
+            plain,
+            path: path.unwrap_or_default(),
+        },
+        "watch" => Command::Watch { options, delay },
+        "solve" => Command::Solve {
+            moves: moves.unwrap_or_default(),
+        },
+        "bench" => Command::Bench {
+            options,
+            positions,
+            plies,
+        },
+        "simulate" => Command::Simulate {
+            options,
+            fixed_start,
+            games,
+            format,
+            output,
+            log,
+        },
+        _ => Command::Help,
+    })
+}
+
+/// Runs the command given on the command line `args` (without the program name).
+///
+/// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+/// `Connect4Error` containing an error message.
+pub fn run(args: Vec<String>) -> Result<(), Connect4Error> {
+    match parse(args)? {
+        Command::Play {
+            options,
+            plain,
+            save_path,
+        } => commands::play(&options, None, plain, Some(&save_path)),
+        Command::Resume {
+            options,
+            plain,
+            path,
+        } => commands::resume(&options, plain, &path),
+        Command::Watch { options, delay } => commands::play(&options, Some(delay), true, None),
+        Command::Solve { moves } => commands::solve(&moves),
+        Command::Bench {
+            options,
+            positions,
+            plies,
+        } => commands::bench(&options, positions, plies),
+        Command::Simulate {
+            options,
+            fixed_start,
+            games,
+            format,
+            output,
+            log,
+        } => commands::simulate(
+            &options,
+            fixed_start,
+            games,
+            format,
+            output.as_deref(),
+            log.as_deref(),
+        ),
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses the whitespace separated arguments in `args`.
+    fn parse_str(args: &str) -> Result<Command, Connect4Error> {
+        parse(args.split_whitespace().map(String::from).collect())
+    }
+
+    /// Returns the message of a `Connect4Error::Parse` for `args`, panicking on anything else.
+    fn parse_error(args: &str) -> String {
+        match parse_str(args) {
+            Err(Connect4Error::Parse(message)) => message,
+            result => panic!("{args:?}: expected a parse error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn play_is_the_default_command() {
+        let Ok(Command::Play {
+            options,
+            plain,
+            save_path,
+        }) = parse_str("")
+        else {
+            panic!("expected a play command");
+        };
+        assert!(!plain);
+        assert_eq!(save_path, "game.c4");
+        assert_eq!(
+            (options.num_rows, options.num_columns, options.amount_to_win),
+            (6, 7, 4)
+        );
+        assert_eq!(
+            options.seats,
+            [
+                SeatKind::Human,
+                SeatKind::Ai(Difficulty::Random),
+                SeatKind::Ai(Difficulty::Random),
+                SeatKind::Ai(Difficulty::Random),
+            ]
+        );
+        assert_eq!(
+            options.seat_names(),
+            ["Player 1", "Bot 1", "Bot 2", "Bot 3"]
+        );
+        assert_eq!(
+            options.seat_colors(),
+            [Color::RED, Color::BLACK, Color::RED, Color::BLACK]
+        );
+        assert_eq!(options.starting_player_index, 0);
+        assert!(matches!(parse_str("--rows 5"), Ok(Command::Play { .. })));
+    }
+
+    #[test]
+    fn play_options_are_parsed() {
+        let Ok(Command::Play {
+            options,
+            plain,
+            save_path,
+        }) = parse_str(
+            "play -r 5 --columns=9 -w 3 -p human -p minimax:4 -n Alice --color black \
+             --color=red -s 2 --seed 7 --plain --save=saved.c4",
+        )
+        else {
+            panic!("expected a play command");
+        };
+        assert_eq!(
+            (options.num_rows, options.num_columns, options.amount_to_win),
+            (5, 9, 3)
+        );
+        assert_eq!(options.seats, [SeatKind::Human, SeatKind::Minimax(4)]);
+        assert_eq!(options.seat_names(), ["Alice", "Bot 1"]);
+        assert_eq!(options.seat_colors(), [Color::BLACK, Color::RED]);
+        assert_eq!(options.starting_player_index, 1);
+        assert_eq!(options.seed, Some(7));
+        assert!(plain);
+        assert_eq!(save_path, "saved.c4");
+        let Ok(Command::Play { options, .. }) = parse_str("play -d hard") else {
+            panic!("expected a play command");
+        };
+        assert_eq!(options.seats[1], SeatKind::Ai(Difficulty::Hard));
+        let Ok(Command::Play { options, .. }) = parse_str("play --colors 256 --compact") else {
+            panic!("expected a play command");
+        };
+        assert_eq!(options.piece_style(), PieceStyle::Discs(ColorMode::Ansi256));
+        assert!(options.compact);
+        let Ok(Command::Watch { options, .. }) = parse_str("watch --colors=none --words") else {
+            panic!("expected a watch command");
+        };
+        assert_eq!(options.color_mode, Some(ColorMode::NoColor));
+        assert_eq!(options.piece_style(), PieceStyle::Words);
+        let Ok(Command::Watch { options, .. }) =
+            parse_str("watch --time fischer:60+2 --clock team")
+        else {
+            panic!("expected a watch command");
+        };
+        assert_eq!(
+            options.time_control,
+            Some(TimeControl::Fischer {
+                initial: Duration::from_secs(60),
+                increment: Duration::from_secs(2),
+            })
+        );
+        assert_eq!(options.clock_scope, ClockScope::Team);
+        let Ok(Command::Watch { options, .. }) =
+            parse_str("watch --teams 3 -p easy -p easy -p easy -p easy --color=teal")
+        else {
+            panic!("expected a watch command");
+        };
+        assert_eq!(
+            options.seat_colors(),
+            [Color::TEAL, Color::BLACK, Color::YELLOW, Color::RED]
+        );
+        let Ok(Command::Play { options, .. }) =
+            parse_str("--team red=Dragons --team=black=Ravens,V --team yellow=Suns,S,#ffcc00")
+        else {
+            panic!("expected a play command");
+        };
+        assert_eq!(
+            options.teams,
+            [
+                (Color::RED, Team::new("Dragons".into(), 'R', (220, 40, 40))),
+                (
+                    Color::BLACK,
+                    Team::new("Ravens".into(), 'V', (120, 120, 120))
+                ),
+                (Color::YELLOW, Team::new("Suns".into(), 'S', (255, 204, 0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn other_commands_are_parsed() {
+        let Ok(Command::Watch { options, delay }) = parse_str("watch --delay 0 -p easy -p hard")
+        else {
+            panic!("expected a watch command");
+        };
+        assert_eq!(delay, Duration::ZERO);
+        assert_eq!(options.seat_names(), ["Bot 1", "Bot 2"]);
+        let Ok(Command::Watch { options, delay }) = parse_str("watch") else {
+            panic!("expected a watch command");
+        };
+        assert_eq!(delay, Duration::from_millis(500));
+        assert_eq!(options.seats, [SeatKind::Ai(Difficulty::Medium); 2]);
+        let Ok(Command::Solve { moves }) = parse_str("solve 4453") else {
+            panic!("expected a solve command");
+        };
+        assert_eq!(moves, "4453");
+        let Ok(Command::Bench {
+            options,
+            positions,
+            plies,
+        }) = parse_str("bench --positions 5 --plies=2 --seed 9 -p mcts:100")
+        else {
+            panic!("expected a bench command");
+        };
+        assert_eq!((positions, plies, options.seed), (5, 2, Some(9)));
+        assert_eq!(options.seats, [SeatKind::Mcts(100)]);
+        let Ok(Command::Bench {
+            options,
+            positions,
+            plies,
+        }) = parse_str("bench")
+        else {
+            panic!("expected a bench command");
+        };
+        assert_eq!((positions, plies, options.seed), (20, 8, None));
+        assert_eq!(
+            options.seats,
+            [SeatKind::Ai(Difficulty::Medium), SeatKind::Minimax(6)]
+        );
+        let Ok(Command::Resume {
+            options,
+            plain,
+            path,
+        }) = parse_str("resume saved.c4 -p hard -n Alice --plain --words")
+        else {
+            panic!("expected a resume command");
+        };
+        assert_eq!(path, "saved.c4");
+        assert!(plain);
+        assert_eq!(options.seats, [SeatKind::Ai(Difficulty::Hard)]);
+        assert_eq!(options.names, ["Alice"]);
+        assert_eq!(options.piece_style(), PieceStyle::Words);
+        let Ok(Command::Resume { options, .. }) = parse_str("resume -n A -n B saved.c4") else {
+            panic!("expected a resume command");
+        };
+        assert!(options.seats.is_empty());
+        assert_eq!(options.names, ["A", "B"]);
+        for args in ["help", "-h", "--help", "watch --help"] {
+            assert!(matches!(parse_str(args), Ok(Command::Help)), "{args}");
+        }
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        for (args, message) in [
+            ("--rows", "Missing value for \"--rows\"."),
+            ("-r six", "Invalid number \"six\" for \"-r\"."),
+            (
+                "--rows=5 extra",
+                "Unexpected argument \"extra\" for \"play\" (see \"connect_4 help\").",
+            ),
+            ("-s 0", "Seat numbers start at 1."),
+            (
+                "play --delay 5",
+                "Unexpected argument \"--delay\" for \"play\" (see \"connect_4 help\").",
+            ),
+            (
+                "solve 4453 4",
+                "Unexpected argument \"4\" for \"solve\" (see \"connect_4 help\").",
+            ),
+            (
+                "watch --plain",
+                "Unexpected argument \"--plain\" for \"watch\" (see \"connect_4 help\").",
+            ),
+            ("--teams 1", "A game needs at least 2 teams."),
+            ("resume", "\"resume\" needs the file of a saved game."),
+            (
+                "resume a.c4 b.c4",
+                "Unexpected argument \"b.c4\" for \"resume\" (see \"connect_4 help\").",
+            ),
+            (
+                "resume a.c4 --delay 5",
+                "Unexpected argument \"--delay\" for \"resume\" (see \"connect_4 help\").",
+            ),
+            (
+                "--colors 8",
+                "Unknown color mode \"8\" (expected auto, none, 16, 256 or truecolor).",
+            ),
+            (
+                "bench --compact",
+                "Unexpected argument \"--compact\" for \"bench\" (see \"connect_4 help\").",
+            ),
+            (
+                "bench --name A",
+                "Unexpected argument \"--name\" for \"bench\" (see \"connect_4 help\").",
+            ),
+            (
+                "-d hard -p human",
+                "--difficulty only applies to the default bots; use --player instead.",
+            ),
+            (
+                "-p human -n A -n B",
+                "There are more --name or --color flags than players (1).",
+            ),
+            (
+                "watch -p human -p hard",
+                "Human players cannot take part in \"watch\".",
+            ),
+            (
+                "bench -p human",
+                "Human players cannot take part in \"bench\".",
+            ),
+        ] {
+            assert_eq!(parse_error(args), message, "{args}");
+        }
+        for args in [
+            "-p robot",
+            "--color mauve",
+            "-d impossible",
+            "--team red",
+            "--team mauve=Dragons",
+            "--team red=Dragons,DD",
+            "--time 60",
+            "--clock board",
+        ] {
+            parse_error(args);
+        }
+        assert!(matches!(
+            parse_str("--teams 17"),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+        // main exits with a failure status whenever run fails
+        for args in ["--rows", "resume /nonexistent/connect_4.c4"] {
+            let args = args.split_whitespace().map(String::from).collect();
+            assert!(run(args).is_err());
+        }
+    }
+}
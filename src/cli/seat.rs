@@ -0,0 +1,128 @@
+use connect_4::{
+    AIPlayer, Budget, Connect4Error, Difficulty, MctsPlayer, MinimaxPlayer, Player, TerminalPlayer,
+};
+
+/// The kind of player sitting in a seat, as given to `--player`.
+///
+/// The accepted forms are `human`, a `Difficulty` level (`random`, `easy`, `medium`, `hard` or
+/// `perfect`), `minimax:<depth>` and `mcts:<iterations>`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SeatKind {
+    Human,
+    Ai(Difficulty),
+    Minimax(usize),
+    Mcts(usize),
+}
+
+impl SeatKind {
+    /// Returns `true` if the seat is played by a person at the terminal.
+    pub fn is_human(&self) -> bool {
+        *self == SeatKind::Human
+    }
+
+    /// Constructs the player for the seat with the given `name`.
+    pub fn build(&self, name: String) -> Box<dyn Player> {
+        match self {
+            SeatKind::Human => Box::new(TerminalPlayer::new(name)),
+            SeatKind::Ai(difficulty) => Box::new(AIPlayer::with_difficulty(name, *difficulty)),
+            SeatKind::Minimax(depth) => Box::new(MinimaxPlayer::new(name, *depth)),
+            SeatKind::Mcts(iterations) => {
+                Box::new(MctsPlayer::new(name, Budget::Iterations(*iterations)))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SeatKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SeatKind::Human => write!(f, "human"),
+            SeatKind::Ai(difficulty) => write!(f, "{}", difficulty.to_string().to_lowercase()),
+            SeatKind::Minimax(depth) => write!(f, "minimax:{depth}"),
+            SeatKind::Mcts(iterations) => write!(f, "mcts:{iterations}"),
+        }
+    }
+}
+
+impl std::str::FromStr for SeatKind {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, arg) = match s.split_once(':') {
+            Some((kind, arg)) => (kind, Some(arg)),
+            None => (s, None),
+        };
+        let parse_arg = |what: &str| -> Result<usize, Connect4Error> {
+            arg.and_then(|arg| arg.parse::<usize>().ok())
+                .filter(|value| *value > 0)
+                .ok_or_else(|| {
+                    Connect4Error::Parse(format!(
+                        "\"{s}\" needs a positive {what}, e.g. \"{kind}:{}\".",
+                        if kind == "mcts" { 5000 } else { 6 }
+                    ))
+                })
+        };
+        match kind.to_lowercase().as_str() {
+            "human" if arg.is_none() => Ok(SeatKind::Human),
+            "minimax" => Ok(SeatKind::Minimax(parse_arg("search depth")?)),
+            "mcts" => Ok(SeatKind::Mcts(parse_arg("iteration count")?)),
+            _ if arg.is_none() => kind.parse().map(SeatKind::Ai).map_err(|_| {
+                Connect4Error::Parse(format!(
+                    "Unknown player type \"{s}\" (expected human, random, easy, medium, hard, \
+                     perfect, minimax:<depth> or mcts:<iterations>)."
+                ))
+            }),
+            _ => Err(Connect4Error::Parse(format!(
+                "The player type \"{kind}\" does not take an argument."
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seats_round_trip() {
+        for (seat_str, seat) in [
+            ("human", SeatKind::Human),
+            ("random", SeatKind::Ai(Difficulty::Random)),
+            ("easy", SeatKind::Ai(Difficulty::Easy)),
+            ("medium", SeatKind::Ai(Difficulty::Medium)),
+            ("hard", SeatKind::Ai(Difficulty::Hard)),
+            ("perfect", SeatKind::Ai(Difficulty::Perfect)),
+            ("minimax:6", SeatKind::Minimax(6)),
+            ("mcts:5000", SeatKind::Mcts(5000)),
+        ] {
+            assert_eq!(seat_str.parse::<SeatKind>().unwrap(), seat);
+            assert_eq!(seat.to_string(), seat_str);
+        }
+        assert_eq!(
+            "Hard".parse::<SeatKind>().unwrap(),
+            SeatKind::Ai(Difficulty::Hard)
+        );
+        assert!(SeatKind::Human.is_human());
+        assert!(!SeatKind::Minimax(2).is_human());
+    }
+
+    #[test]
+    fn invalid_seats_are_rejected() {
+        for seat_str in [
+            "",
+            "robot",
+            "human:2",
+            "hard:3",
+            "minimax",
+            "minimax:0",
+            "minimax:deep",
+            "mcts:",
+            "mcts:-5",
+        ] {
+            assert!(
+                matches!(seat_str.parse::<SeatKind>(), Err(Connect4Error::Parse(_))),
+                "{seat_str:?}"
+            );
+        }
+    }
+}
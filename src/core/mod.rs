@@ -1,14 +0,0 @@
-mod color;
-pub use color::Color;
-
-mod board;
-pub use board::Board;
-
-mod game;
-pub use game::Game;
-
-mod players;
-pub use players::*;
-
-mod utils;
-pub use utils::*;
@@ -1,8 +0,0 @@
-mod player;
-pub use player::Player;
-
-mod ai_player;
-pub use ai_player::AIPlayer;
-
-mod terminal_player;
-pub use terminal_player::TerminalPlayer;
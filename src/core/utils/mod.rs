@@ -1,2 +0,0 @@
-mod clear_screen;
-pub use clear_screen::clear_screen;
@@ -0,0 +1,94 @@
+use std::fmt;
+
+/// The error type returned by every fallible operation in the crate.
+///
+/// Each variant describes one failure mode so that callers can react to, for example, a full
+/// column differently than to a closed standard input. The `Display` implementation gives a
+/// message which can be shown to the user as is.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Connect4Error {
+    /// A piece was dropped into the column with index `col_index`, which is already full.
+    ColumnFull { col_index: usize },
+    /// The column index `col_index` does not exist on a board with `num_columns` columns.
+    ColumnOutOfRange {
+        col_index: usize,
+        num_columns: usize,
+    },
+    /// A piece was removed from the column with index `col_index`, which is empty.
+    ColumnEmpty { col_index: usize },
+    /// A move was requested on a board without any available columns.
+    BoardFull,
+    /// The board could not be printed because a piece needs `required` characters but the
+    /// columns are only `column_width` characters wide.
+    InadequateColumnWidth {
+        column_width: usize,
+        required: usize,
+    },
+    /// Standard input was closed while a player was being prompted for a move, which forfeits
+    /// the game for the player (see `EndReason::Forfeit`).
+    StdinClosed,
+    /// Reading from or writing to the terminal or a file failed.
+    Io(std::io::Error),
+    /// The settings of a game, board or player are invalid.
+    InvalidConfig(String),
+    /// Text in one of the crate's notations or formats could not be parsed.
+    Parse(String),
+    /// A player gave up on deciding on an action, such as by quitting, with a message given by
+    /// the player. Returned by a player, this forfeits the game (see `EndReason::Forfeit`).
+    Player(String),
+    /// An operation is not possible in the current state of the game, such as resuming an ended
+    /// game or undoing when no moves were played.
+    InvalidState(String),
+}
+
+impl fmt::Display for Connect4Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Connect4Error::ColumnFull { col_index } => {
+                write!(f, "The column with index {col_index} is full.")
+            }
+            Connect4Error::ColumnOutOfRange {
+                col_index,
+                num_columns,
+            } => write!(
+                f,
+                "The column index {col_index} is out of range for a board with {num_columns} \
+                 columns."
+            ),
+            Connect4Error::ColumnEmpty { col_index } => {
+                write!(f, "The column with index {col_index} is empty.")
+            }
+            Connect4Error::BoardFull => write!(f, "There are no available columns."),
+            Connect4Error::InadequateColumnWidth {
+                column_width,
+                required,
+            } => write!(
+                f,
+                "Inadequate column width: {required} characters are needed but columns are \
+                 {column_width} wide."
+            ),
+            Connect4Error::StdinClosed => write!(f, "Standard input was closed."),
+            Connect4Error::Io(e) => write!(f, "I/O error: {e}"),
+            Connect4Error::InvalidConfig(msg)
+            | Connect4Error::Parse(msg)
+            | Connect4Error::Player(msg)
+            | Connect4Error::InvalidState(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Connect4Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Connect4Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Connect4Error {
+    fn from(e: std::io::Error) -> Self {
+        Connect4Error::Io(e)
+    }
+}
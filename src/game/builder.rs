@@ -0,0 +1,405 @@
+use crate::{
+    Board, ClockScope, Color, Connect4Error, Game, GameObserver, Player, Team, TimeControl,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Configures and validates a `Game` before it is constructed.
+///
+/// Every setting has the same default as `Game::new` with `None` as the board: a 6x7 board
+/// printed with 3 line rows and 7 character columns, a win length of 4, the first player
+/// starting, no time limit and no observers, so that the game prints nothing.
+///
+/// # Examples
+///
+/// ```
+/// use connect_4::{AIPlayer, Color, GameBuilder, TerminalPlayer};
+///
+/// # fn main() -> Result<(), connect_4::Connect4Error> {
+/// let game = GameBuilder::new()
+///     .board_size(5, 6)
+///     .amount_to_win(3)
+///     .player(Box::new(TerminalPlayer::new("Player 1".into())), Color::RED)
+///     .player(Box::new(AIPlayer::new("Bot 1".into())), Color::BLACK)
+///     .starting_player(1)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct GameBuilder {
+    num_rows: usize,
+    num_columns: usize,
+    row_height: usize,
+    column_width: usize,
+    amount_to_win: usize,
+    players: Vec<Box<dyn Player>>,
+    player_colors: Vec<Color>,
+    starting_player_index: usize,
+    time_control: Option<TimeControl>,
+    clock_scope: ClockScope,
+    observers: Vec<Box<dyn GameObserver>>,
+    seed: Option<u64>,
+    save_path: Option<PathBuf>,
+    teams: Vec<(Color, Team)>,
+}
+
+impl Default for GameBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameBuilder {
+    /// Constructs a `GameBuilder` with the default settings and no players.
+    pub fn new() -> Self {
+        GameBuilder {
+            num_rows: 6,
+            num_columns: 7,
+            row_height: 3,
+            column_width: 7,
+            amount_to_win: 4,
+            players: Vec::new(),
+            player_colors: Vec::new(),
+            starting_player_index: 0,
+            time_control: None,
+            clock_scope: ClockScope::Player,
+            observers: Vec::new(),
+            seed: None,
+            save_path: None,
+            teams: Vec::new(),
+        }
+    }
+
+    /// Sets the number of rows and columns of the board.
+    pub fn board_size(mut self, num_rows: usize, num_columns: usize) -> Self {
+        self.num_rows = num_rows;
+        self.num_columns = num_columns;
+        self
+    }
+
+    /// Sets the height in lines of each printed row and the width in characters of each printed
+    /// column.
+    pub fn render_size(mut self, row_height: usize, column_width: usize) -> Self {
+        self.row_height = row_height;
+        self.column_width = column_width;
+        self
+    }
+
+    /// Presents the team of `color` as `team`, with a custom name, glyph and RGB value, instead
+    /// of the color's built-in presentation (see `Team::default_for`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::{AIPlayer, Color, GameBuilder, Team};
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let game = GameBuilder::new()
+    ///     .player(Box::new(AIPlayer::new("Bot 1".into())), Color::RED)
+    ///     .player(Box::new(AIPlayer::new("Bot 2".into())), Color::BLACK)
+    ///     .team(Color::RED, Team::new("Dragons".into(), 'D', (255, 102, 0)))
+    ///     .build()?;
+    /// assert_eq!(game.get_board().get_team(Color::RED).name, "Dragons");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn team(mut self, color: Color, team: Team) -> Self {
+        self.teams.retain(|(other, _)| *other != color);
+        self.teams.push((color, team));
+        self
+    }
+
+    /// Sets the number of pieces a team must get in a row to win the game.
+    pub fn amount_to_win(mut self, amount_to_win: usize) -> Self {
+        self.amount_to_win = amount_to_win;
+        self
+    }
+
+    /// Adds a player on the team of `color` after the players added so far.
+    ///
+    /// Any number of players can share a team, but a game has at most `Color::COUNT` (16)
+    /// teams as that is the number of `Color`s there are.
+    pub fn player(mut self, player: Box<dyn Player>, color: Color) -> Self {
+        self.players.push(player);
+        self.player_colors.push(color);
+        self
+    }
+
+    /// Adds several players after the players added so far, each on the team of the `Color` at
+    /// the corresponding index in `player_colors`.
+    pub fn players(mut self, players: Vec<Box<dyn Player>>, player_colors: Vec<Color>) -> Self {
+        self.players.extend(players);
+        self.player_colors.extend(player_colors);
+        self
+    }
+
+    /// Sets the index of the player who makes the first move.
+    pub fn starting_player(mut self, starting_player_index: usize) -> Self {
+        self.starting_player_index = starting_player_index;
+        self
+    }
+
+    /// Sets the time each player is given to decide on a move, which is a shorthand for
+    /// `time_control` with `TimeControl::PerMove`.
+    pub fn time_per_move(mut self, time_per_move: Duration) -> Self {
+        self.time_control = Some(TimeControl::PerMove {
+            limit: time_per_move,
+        });
+        self
+    }
+
+    /// Times the game under `time_control` (see `Game::set_time_control`). Games are untimed by
+    /// default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::{AIPlayer, ClockScope, Color, GameBuilder, TimeControl};
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let game = GameBuilder::new()
+    ///     .player(Box::new(AIPlayer::new("Bot 1".into())), Color::RED)
+    ///     .player(Box::new(AIPlayer::new("Bot 2".into())), Color::BLACK)
+    ///     .time_control(TimeControl::Fischer {
+    ///         initial: Duration::from_secs(60),
+    ///         increment: Duration::from_secs(2),
+    ///     })
+    ///     .clock_scope(ClockScope::Team)
+    ///     .build()?;
+    /// assert_eq!(game.get_clock().unwrap().time_left(0), Duration::from_secs(60));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn time_control(mut self, time_control: TimeControl) -> Self {
+        self.time_control = Some(time_control);
+        self
+    }
+
+    /// Sets whether every player or every team has a clock of their own in a timed game.
+    /// Defaults to `ClockScope::Player`.
+    pub fn clock_scope(mut self, clock_scope: ClockScope) -> Self {
+        self.clock_scope = clock_scope;
+        self
+    }
+
+    /// Sets the seed every player's seed is derived from (see `Game::set_seed`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::{AIPlayer, Color, Connect4Error, Difficulty, GameBuilder};
+    ///
+    /// # fn main() -> Result<(), Connect4Error> {
+    /// let play = |seed| -> Result<Vec<usize>, Connect4Error> {
+    ///     let mut game = GameBuilder::new()
+    ///         .player(
+    ///             Box::new(AIPlayer::with_difficulty("Bot 1".into(), Difficulty::Easy)),
+    ///             Color::RED,
+    ///         )
+    ///         .player(Box::new(AIPlayer::new("Bot 2".into())), Color::BLACK)
+    ///         .seed(seed)
+    ///         .build()?;
+    ///     Ok(game.start()?.moves)
+    /// };
+    /// assert_eq!(play(42)?, play(42)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the file the game is written to when a player chooses `Action::Save` (see
+    /// `Game::set_save_path`).
+    pub fn save_path<P: Into<PathBuf>>(mut self, save_path: P) -> Self {
+        self.save_path = Some(save_path.into());
+        self
+    }
+
+    /// Attaches an `observer` which is notified of every `GameEvent` of the game (see
+    /// `GameObserver`), such as a `TerminalObserver` to show the game in the terminal.
+    pub fn observer(mut self, observer: Box<dyn GameObserver>) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// Returns a `Result` type with a unit `Ok` if the settings describe a playable game or an
+    /// `Err` with a `Connect4Error::InvalidConfig` describing the first problem found.
+    ///
+    /// The number of teams needs no check: players can only be put on one of the
+    /// `Color::COUNT` (16) teams that exist.
+    fn validate(&self) -> Result<(), Connect4Error> {
+        if self.num_rows == 0 || self.num_columns == 0 {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "The board must have at least one row and one column (got {}x{}).",
+                self.num_rows, self.num_columns
+            )));
+        }
+        if self.row_height == 0 {
+            return Err(Connect4Error::InvalidConfig(
+                "The row height must be at least 1 line.".into(),
+            ));
+        }
+        if self.column_width == 0 {
+            return Err(Connect4Error::InvalidConfig(
+                "The column width must be at least 1 character.".into(),
+            ));
+        }
+        if self.amount_to_win == 0 {
+            return Err(Connect4Error::InvalidConfig(
+                "The win length must be at least 1.".into(),
+            ));
+        }
+        if self.amount_to_win > self.num_rows && self.amount_to_win > self.num_columns {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "The win length {} is larger than both dimensions of the {}x{} board.",
+                self.amount_to_win, self.num_rows, self.num_columns
+            )));
+        }
+        if self.players.is_empty() {
+            return Err(Connect4Error::InvalidConfig(
+                "The game must have at least one player.".into(),
+            ));
+        }
+        if self.players.len() != self.player_colors.len() {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "There are {} players but {} player colors.",
+                self.players.len(),
+                self.player_colors.len()
+            )));
+        }
+        if self.starting_player_index >= self.players.len() {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "The starting player index {} is out of range for {} players.",
+                self.starting_player_index,
+                self.players.len()
+            )));
+        }
+        if self
+            .time_control
+            .is_some_and(|time_control| time_control.initial().is_zero())
+        {
+            return Err(Connect4Error::InvalidConfig(
+                "Players must be given some time to move.".into(),
+            ));
+        }
+        for (_, team) in self.teams.iter() {
+            team.validate()?;
+        }
+        let mut colors: Vec<Color> = Vec::new();
+        for color in self.player_colors.iter() {
+            if !colors.contains(color) {
+                colors.push(*color);
+            }
+        }
+        let teams = colors
+            .iter()
+            .map(|color| {
+                self.teams
+                    .iter()
+                    .find(|(other, _)| other == color)
+                    .map_or_else(|| Team::default_for(*color), |(_, team)| team.clone())
+            })
+            .collect::<Vec<Team>>();
+        for (i, team) in teams.iter().enumerate() {
+            if let Some(other) = teams[..i].iter().find(|other| other.glyph == team.glyph) {
+                return Err(Connect4Error::InvalidConfig(format!(
+                    "The teams {} and {} share the glyph '{}'.",
+                    other.name, team.name, team.glyph
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Constructs the configured `Game`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Game` instance or an `Err` with a
+    /// `Connect4Error::InvalidConfig` describing why the settings are invalid.
+    pub fn build(self) -> Result<Game, Connect4Error> {
+        self.validate()?;
+        let mut board = Board::new(
+            self.num_rows,
+            self.num_columns,
+            self.row_height,
+            self.column_width,
+        );
+        for (color, team) in self.teams {
+            board.set_team(color, team);
+        }
+        let mut game = Game::new(Some(board), self.players, self.player_colors)?;
+        game.amount_to_win = self.amount_to_win;
+        game.current_player_index = self.starting_player_index;
+        game.set_time_control(self.time_control, self.clock_scope);
+        game.observers = self.observers;
+        game.save_path = self.save_path;
+        if let Some(seed) = self.seed {
+            game.set_seed(seed);
+        }
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AIPlayer;
+
+    /// Returns a builder with a red and a black computer player.
+    fn two_players() -> GameBuilder {
+        GameBuilder::new()
+            .player(Box::new(AIPlayer::new("Red".into())), Color::RED)
+            .player(Box::new(AIPlayer::new("Black".into())), Color::BLACK)
+    }
+
+    #[test]
+    fn valid_settings_build() {
+        assert!(two_players().build().is_ok());
+        assert!(two_players()
+            .board_size(3, 12)
+            .amount_to_win(5)
+            .starting_player(1)
+            .build()
+            .is_ok());
+        assert!(two_players().render_size(1, 1).build().is_ok());
+        let dragons = Team::new("Dragons".into(), 'D', (255, 102, 0));
+        let game = two_players()
+            .team(Color::RED, dragons.clone())
+            .build()
+            .unwrap();
+        assert_eq!(game.get_board().get_team(Color::RED), dragons);
+        assert_eq!(game.get_board().get_custom_teams(), [(Color::RED, dragons)]);
+    }
+
+    #[test]
+    fn invalid_settings_are_rejected() {
+        assert!(two_players().board_size(0, 7).build().is_err());
+        assert!(two_players().render_size(0, 7).build().is_err());
+        assert!(two_players().amount_to_win(0).build().is_err());
+        assert!(two_players()
+            .board_size(4, 5)
+            .amount_to_win(6)
+            .build()
+            .is_err());
+        assert!(GameBuilder::new().build().is_err());
+        assert!(two_players().starting_player(2).build().is_err());
+        assert!(two_players().render_size(3, 0).build().is_err());
+        assert!(two_players()
+            .time_control(TimeControl::SuddenDeath {
+                initial: Duration::ZERO
+            })
+            .build()
+            .is_err());
+        let team = |name: &str, glyph| Team::new(name.into(), glyph, (0, 0, 0));
+        assert!(two_players()
+            .team(Color::RED, team("Blue", 'B'))
+            .build()
+            .is_err());
+        assert!(two_players()
+            .team(Color::RED, team("", 'X'))
+            .build()
+            .is_err());
+    }
+}
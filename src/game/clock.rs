@@ -0,0 +1,442 @@
+use crate::{Board, Color, Connect4Error};
+use std::time::Duration;
+
+/// How much time the players of a timed game are given to decide on their moves.
+///
+/// The text form used by `Display`, `FromStr`, `GameRecord` and the command line gives times in
+/// seconds: `sudden:<initial>`, `fischer:<initial>+<increment>` or `move:<limit>`.
+///
+/// # Examples
+///
+/// ```
+/// use connect_4::TimeControl;
+/// use std::time::Duration;
+///
+/// let control: TimeControl = "fischer:300+5".parse().unwrap();
+/// assert_eq!(
+///     control,
+///     TimeControl::Fischer {
+///         initial: Duration::from_secs(300),
+///         increment: Duration::from_secs(5),
+///     }
+/// );
+/// assert_eq!(control.to_string(), "fischer:300+5");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeControl {
+    /// Every clock starts at `initial` and its owner loses on time when it runs out.
+    SuddenDeath { initial: Duration },
+    /// Like `SuddenDeath`, but `increment` is added to a clock after every piece its owner
+    /// drops.
+    Fischer {
+        initial: Duration,
+        increment: Duration,
+    },
+    /// Every turn must be finished within `limit`; time left over is not carried over.
+    PerMove { limit: Duration },
+}
+
+impl TimeControl {
+    /// Returns the time on every clock when the game starts.
+    pub fn initial(&self) -> Duration {
+        match *self {
+            TimeControl::SuddenDeath { initial } | TimeControl::Fischer { initial, .. } => initial,
+            TimeControl::PerMove { limit } => limit,
+        }
+    }
+
+    /// Returns the time added to a clock after every piece its owner drops.
+    pub fn increment(&self) -> Duration {
+        match *self {
+            TimeControl::Fischer { increment, .. } => increment,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let secs = |duration: Duration| duration.as_secs_f64();
+        match *self {
+            TimeControl::SuddenDeath { initial } => write!(f, "sudden:{}", secs(initial)),
+            TimeControl::Fischer { initial, increment } => {
+                write!(f, "fischer:{}+{}", secs(initial), secs(increment))
+            }
+            TimeControl::PerMove { limit } => write!(f, "move:{}", secs(limit)),
+        }
+    }
+}
+
+impl std::str::FromStr for TimeControl {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            Connect4Error::Parse(format!(
+                "Unknown time control \"{}\" (expected sudden:<secs>, fischer:<secs>+<secs> or \
+                 move:<secs>).",
+                s.trim()
+            ))
+        };
+        let secs = |value: &str| {
+            value
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                .ok_or_else(invalid)
+        };
+        let (kind, value) = s.trim().split_once(':').ok_or_else(invalid)?;
+        let control = match kind.to_lowercase().as_str() {
+            "sudden" => TimeControl::SuddenDeath {
+                initial: secs(value)?,
+            },
+            "fischer" => {
+                let (initial, increment) = value.split_once('+').ok_or_else(invalid)?;
+                TimeControl::Fischer {
+                    initial: secs(initial)?,
+                    increment: secs(increment)?,
+                }
+            }
+            "move" => TimeControl::PerMove {
+                limit: secs(value)?,
+            },
+            _ => return Err(invalid()),
+        };
+        if control.initial().is_zero() {
+            return Err(Connect4Error::Parse(
+                "Players must be given some time to move.".into(),
+            ));
+        }
+        Ok(control)
+    }
+}
+
+/// Whether every player of a timed game has a clock of their own or the players of a team
+/// share one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClockScope {
+    /// Every player has their own clock.
+    #[default]
+    Player,
+    /// The players of each team share a clock.
+    Team,
+}
+
+impl std::fmt::Display for ClockScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ClockScope::Player => write!(f, "player"),
+            ClockScope::Team => write!(f, "team"),
+        }
+    }
+}
+
+impl std::str::FromStr for ClockScope {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "player" => Ok(ClockScope::Player),
+            "team" => Ok(ClockScope::Team),
+            _ => Err(Connect4Error::Parse(format!(
+                "Unknown clock scope \"{}\" (expected player or team).",
+                s.trim()
+            ))),
+        }
+    }
+}
+
+/// Who a clock of a `GameClock` belongs to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ClockOwner {
+    /// The player at the given index.
+    Player(usize),
+    /// Every player on the team of the given `Color`.
+    Team(Color),
+}
+
+impl ClockOwner {
+    /// Returns the name the owner is shown by: the player's name from `player_names` or the
+    /// team's name on `board`.
+    pub fn label(&self, board: &Board, player_names: &[String]) -> String {
+        match *self {
+            ClockOwner::Player(player_index) => player_names
+                .get(player_index)
+                .cloned()
+                .unwrap_or_else(|| format!("Player {}", player_index + 1)),
+            ClockOwner::Team(color) => board.get_team(color).name,
+        }
+    }
+}
+
+/// The clocks of a timed game, which run down while their owners decide on their moves.
+///
+/// A `Game` charges the time a player takes for every action to the player's clock (see
+/// `GameBuilder::time_control`); a player who takes longer than the time on their clock loses
+/// on time on behalf of their team.
+///
+/// Players are never interrupted: the time is charged once `Player::get_action` returns, so a
+/// player who overruns their clock only loses when they finally answer. Computer players keep
+/// to `TurnContext::time_budget` to stay within their time.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GameClock {
+    control: TimeControl,
+    scope: ClockScope,
+    /// The index in `owners` of the clock of every player.
+    clock_indices: Vec<usize>,
+    /// The owner of every clock.
+    owners: Vec<ClockOwner>,
+    /// The time left on every clock.
+    remaining: Vec<Duration>,
+}
+
+impl GameClock {
+    /// Constructs the clocks of a game under `control` between players on the teams of
+    /// `player_colors`, with a clock per player or per team as given by `scope`.
+    pub fn new(control: TimeControl, scope: ClockScope, player_colors: &[Color]) -> Self {
+        let mut owners = Vec::new();
+        let mut clock_indices = Vec::new();
+        for (player_index, color) in player_colors.iter().enumerate() {
+            let owner = match scope {
+                ClockScope::Player => ClockOwner::Player(player_index),
+                ClockScope::Team => ClockOwner::Team(*color),
+            };
+            let clock_index = owners
+                .iter()
+                .position(|other| *other == owner)
+                .unwrap_or_else(|| {
+                    owners.push(owner);
+                    owners.len() - 1
+                });
+            clock_indices.push(clock_index);
+        }
+        GameClock {
+            control,
+            scope,
+            clock_indices,
+            remaining: vec![control.initial(); owners.len()],
+            owners,
+        }
+    }
+
+    /// Returns the `TimeControl` the clocks run under.
+    pub fn get_control(&self) -> TimeControl {
+        self.control
+    }
+
+    /// Returns whether the clocks belong to players or teams.
+    pub fn get_scope(&self) -> ClockScope {
+        self.scope
+    }
+
+    /// Returns the owner and the time left of every clock, ordered by the index of the first
+    /// player each clock belongs to.
+    pub fn get_clocks(&self) -> Vec<(ClockOwner, Duration)> {
+        self.owners
+            .iter()
+            .copied()
+            .zip(self.remaining.iter().copied())
+            .collect()
+    }
+
+    /// Returns the index in `get_clocks` of the clock of the player at `player_index`.
+    pub fn get_clock_index(&self, player_index: usize) -> usize {
+        self.clock_indices[player_index]
+    }
+
+    /// Returns the time the player at `player_index` has left for their turn.
+    pub fn time_left(&self, player_index: usize) -> Duration {
+        self.remaining[self.clock_indices[player_index]]
+    }
+
+    /// Returns the time left on every clock, in the order of `get_clocks`.
+    pub(crate) fn snapshot(&self) -> Vec<Duration> {
+        self.remaining.clone()
+    }
+
+    /// Sets the time left on every clock to `remaining`, in the order of `get_clocks`, as
+    /// returned by `snapshot`. Snapshots with a different number of clocks are ignored.
+    pub(crate) fn restore(&mut self, remaining: &[Duration]) {
+        if remaining.len() == self.remaining.len() {
+            self.remaining = remaining.to_vec();
+        }
+    }
+
+    /// Charges `elapsed` to the clock of the player at `player_index`, adding the increment if
+    /// `dropped` is `true` because the player dropped a piece.
+    ///
+    /// Returns `false` without changing the clock if the player ran out of time, else `true`.
+    pub(crate) fn charge(&mut self, player_index: usize, elapsed: Duration, dropped: bool) -> bool {
+        let clock = &mut self.remaining[self.clock_indices[player_index]];
+        let Some(left) = clock.checked_sub(elapsed) else {
+            return false;
+        };
+        *clock = match self.control {
+            TimeControl::PerMove { limit } => limit,
+            _ if dropped => left + self.control.increment(),
+            _ => left,
+        };
+        true
+    }
+
+    /// Returns every clock as `<owner> <time left>`, joined by `separator`, with the owners
+    /// labelled as by `ClockOwner::label`.
+    pub fn format_clocks(&self, board: &Board, player_names: &[String], separator: &str) -> String {
+        self.get_clocks()
+            .into_iter()
+            .map(|(owner, left)| {
+                format!(
+                    "{} {}",
+                    owner.label(board, player_names),
+                    GameClock::format_time(left)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+
+    /// Returns `duration` formatted for display as minutes, seconds and tenths, e.g. `4:05.3`.
+    pub fn format_time(duration: Duration) -> String {
+        let tenths = duration.as_millis() / 100;
+        format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLORS: [Color; 3] = [Color::RED, Color::BLACK, Color::RED];
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    fn times(clock: &GameClock) -> Vec<Duration> {
+        clock
+            .get_clocks()
+            .into_iter()
+            .map(|(_, left)| left)
+            .collect()
+    }
+
+    #[test]
+    fn charge_adds_the_increment_for_dropped_pieces() {
+        let control = TimeControl::Fischer {
+            initial: secs(60),
+            increment: secs(5),
+        };
+        let mut clock = GameClock::new(control, ClockScope::Player, &COLORS);
+        assert!(clock.charge(0, secs(10), true));
+        assert!(clock.charge(1, secs(10), false));
+        assert_eq!(times(&clock), [secs(55), secs(50), secs(60)]);
+        assert_eq!(clock.time_left(0), secs(55));
+    }
+
+    #[test]
+    fn charge_fails_on_overrun_without_changing_the_clock() {
+        let control = TimeControl::SuddenDeath { initial: secs(60) };
+        let mut clock = GameClock::new(control, ClockScope::Player, &COLORS);
+        assert!(clock.charge(0, secs(45), true));
+        assert_eq!(clock.time_left(0), secs(15));
+        assert!(!clock.charge(0, secs(16), true));
+        assert_eq!(clock.time_left(0), secs(15));
+        assert!(clock.charge(0, secs(15), true));
+        assert_eq!(clock.time_left(0), Duration::ZERO);
+    }
+
+    #[test]
+    fn per_move_limits_reset_every_turn() {
+        let control = TimeControl::PerMove { limit: secs(10) };
+        let mut clock = GameClock::new(control, ClockScope::Player, &COLORS);
+        assert!(clock.charge(1, secs(9), true));
+        assert_eq!(clock.time_left(1), secs(10));
+        assert!(!clock.charge(1, secs(11), true));
+    }
+
+    #[test]
+    fn team_clocks_are_shared() {
+        let control = TimeControl::SuddenDeath { initial: secs(60) };
+        let mut clock = GameClock::new(control, ClockScope::Team, &COLORS);
+        assert_eq!(
+            clock.get_clocks(),
+            [
+                (ClockOwner::Team(Color::RED), secs(60)),
+                (ClockOwner::Team(Color::BLACK), secs(60))
+            ]
+        );
+        assert_eq!(clock.get_clock_index(2), 0);
+        assert!(clock.charge(0, secs(20), true));
+        assert!(clock.charge(2, secs(20), true));
+        assert_eq!(clock.time_left(2), secs(20));
+        assert_eq!(clock.time_left(1), secs(60));
+    }
+
+    #[test]
+    fn snapshots_restore_every_clock() {
+        let control = TimeControl::SuddenDeath { initial: secs(60) };
+        let mut clock = GameClock::new(control, ClockScope::Player, &COLORS);
+        let snapshot = clock.snapshot();
+        assert!(clock.charge(1, secs(20), true));
+        clock.restore(&snapshot);
+        assert_eq!(times(&clock), [secs(60); 3]);
+        clock.restore(&[secs(1)]);
+        assert_eq!(times(&clock), [secs(60); 3]);
+    }
+
+    #[test]
+    fn time_controls_round_trip_through_text() {
+        for (text, control) in [
+            (
+                "sudden:300",
+                TimeControl::SuddenDeath { initial: secs(300) },
+            ),
+            (
+                "fischer:180+2",
+                TimeControl::Fischer {
+                    initial: secs(180),
+                    increment: secs(2),
+                },
+            ),
+            (
+                "move:0.5",
+                TimeControl::PerMove {
+                    limit: Duration::from_millis(500),
+                },
+            ),
+        ] {
+            assert_eq!(text.parse::<TimeControl>().unwrap(), control);
+            assert_eq!(control.to_string(), text);
+        }
+        assert_eq!(
+            " Sudden:60 ".parse::<TimeControl>().unwrap(),
+            TimeControl::SuddenDeath { initial: secs(60) }
+        );
+        for text in [
+            "sudden",
+            "sudden:-1",
+            "fischer:60",
+            "hourglass:60",
+            "move:0",
+        ] {
+            assert!(
+                matches!(text.parse::<TimeControl>(), Err(Connect4Error::Parse(_))),
+                "{text}"
+            );
+        }
+        assert_eq!("team".parse::<ClockScope>().unwrap(), ClockScope::Team);
+        assert!("board".parse::<ClockScope>().is_err());
+    }
+
+    #[test]
+    fn times_are_formatted_with_tenths() {
+        assert_eq!(
+            GameClock::format_time(Duration::from_millis(245_380)),
+            "4:05.3"
+        );
+        assert_eq!(GameClock::format_time(Duration::ZERO), "0:00.0");
+    }
+}
@@ -0,0 +1,1430 @@
+//! Running games between players, along with their outcomes and records.
+
+mod builder;
+pub use builder::GameBuilder;
+
+mod clock;
+pub use clock::{ClockOwner, ClockScope, GameClock, TimeControl};
+
+mod outcome;
+pub use outcome::{EndReason, GameOutcome};
+
+mod observer;
+pub use observer::{GameEvent, GameObserver};
+
+mod record;
+pub use record::GameRecord;
+
+use crate::{Action, Board, Color, Connect4Error, Player, Team, TurnContext};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Represents a singular game with players and a board which can be played.
+pub struct Game {
+    /// The `Board` instance that the game is being played on.
+    board: Board,
+    /// The players participating in the game.
+    players: Vec<Box<dyn Player>>,
+    /// The color team that the players in the corresponding index are on.
+    player_colors: Vec<Color>,
+    /// The index that describes who the game's current active player is.
+    current_player_index: usize,
+    /// The number of pieces a team must get in a row to win the game (4 by default).
+    amount_to_win: usize,
+    /// Whether or not the game has started.
+    started: bool,
+    /// Whether or not the game has ended.
+    ended: bool,
+    /// The result of the game once it has ended.
+    outcome: Option<GameOutcome>,
+    /// The column indices of every move played so far in order.
+    moves: Vec<usize>,
+    /// The column indices of the moves taken back with `undo`, the most recently undone last.
+    undone_moves: Vec<usize>,
+    /// The time left on every clock at the start of the turn of each move in `moves`, so that
+    /// `undo` can give back the time spent on it. Empty snapshots for untimed games.
+    clock_history: Vec<Vec<Duration>>,
+    /// The time left on every clock when each move in `undone_moves` was taken back, so that
+    /// `redo` can charge the time spent on it again.
+    undone_clocks: Vec<Vec<Duration>>,
+    /// The clocks of the players or teams, or `None` for untimed games.
+    clock: Option<GameClock>,
+    /// The indices of the player whose draw offer was declined during their current turn, who
+    /// cannot offer another draw until they drop a piece, and of the player who declined it.
+    declined_draw_offer: Option<(usize, usize)>,
+    /// The observers notified of every `GameEvent`, such as a `TerminalObserver` showing the game.
+    observers: Vec<Box<dyn GameObserver>>,
+    /// The seed the players' seeds were derived from, or `None` if the game was not seeded.
+    seed: Option<u64>,
+    /// The file the game is written to when a player chooses `Action::Save`.
+    save_path: Option<PathBuf>,
+}
+
+impl Game {
+    /// Constructs a game instance which can be started to be played.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - pass `Some(board)` to play with a custom sized or preinstantiated board
+    ///   or pass `None` for an automatic default sized board.
+    ///
+    /// * `players` - a `Vec` of `Box<dyn Player>`s which will play in their present order
+    ///   in the list.
+    ///
+    /// * `player_colors` - a `Vec` of `Color`s of the same length as `players` which denotes
+    ///   the team of each player at the corresponding index in `players`.
+    ///
+    /// Returns a `Result` type with a `Ok` containing the `Game` instance to indicate a success or
+    /// an `Err` with a `Connect4Error` containing an error message if invalid inputs are passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::{Color, Game, Player, TerminalPlayer};
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let players: Vec<Box<dyn Player>> = vec![
+    ///     Box::new(TerminalPlayer::new("Player 1".into())),
+    ///     Box::new(TerminalPlayer::new("Player 2".into())),
+    /// ];
+    /// let player_colors = vec![Color::RED, Color::BLACK];
+    ///
+    /// let mut game = Game::new(None, players, player_colors)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        board: Option<Board>,
+        players: Vec<Box<dyn Player>>,
+        player_colors: Vec<Color>,
+    ) -> Result<Self, Connect4Error> {
+        if players.len() != player_colors.len() {
+            return Err(Connect4Error::InvalidConfig(
+                "Player list and player color list's lengths do not match".to_string(),
+            ));
+        }
+        Ok(Game {
+            board: {
+                match board {
+                    Some(board) => board,
+                    None => Board::new(6, 7, 3, 7),
+                }
+            },
+            players,
+            player_colors,
+            current_player_index: 0,
+            amount_to_win: 4,
+            started: false,
+            ended: false,
+            outcome: None,
+            moves: Vec::new(),
+            undone_moves: Vec::new(),
+            clock_history: Vec::new(),
+            undone_clocks: Vec::new(),
+            clock: None,
+            declined_draw_offer: None,
+            observers: Vec::new(),
+            seed: None,
+            save_path: None,
+        })
+    }
+
+    /// Returns a `GameBuilder` for configuring a game step by step.
+    pub fn builder() -> GameBuilder {
+        GameBuilder::new()
+    }
+
+    /// Returns a reference to the player at `index` in the game's queue.
+    fn get_player(&self, index: usize) -> &dyn Player {
+        &*self.players[index]
+    }
+
+    /// Returns the `Color` corresponding with the game's current active player.
+    fn get_current_color(&self) -> Color {
+        self.player_colors[self.current_player_index]
+    }
+
+    /// Returns the indices of all players in the game with the given `Color`.
+    fn get_player_indices_with_color(&self, color: Color) -> Vec<usize> {
+        self.player_colors
+            .iter()
+            .enumerate()
+            .filter(|(_, player_color)| **player_color == color)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Increments the `current_player_index` which switches the game's active player.
+    fn switch_turn(&mut self) {
+        if self.current_player_index == self.players.len() - 1 {
+            self.current_player_index = 0;
+        } else {
+            self.current_player_index += 1;
+        }
+    }
+
+    /// Decrements the `current_player_index` which switches the game's active player back to the
+    /// previous player.
+    fn switch_turn_back(&mut self) {
+        if self.current_player_index == 0 {
+            self.current_player_index = self.players.len() - 1;
+        } else {
+            self.current_player_index -= 1;
+        }
+    }
+
+    /// Sets the time each player is given to decide on a move, which is passed on to the players
+    /// so that they can budget their thinking time. Pass `None` for an untimed game.
+    ///
+    /// This is a shorthand for `set_time_control` with `TimeControl::PerMove`.
+    pub fn set_time_per_move(&mut self, time_per_move: Option<Duration>) {
+        let time_control = time_per_move.map(|limit| TimeControl::PerMove { limit });
+        self.set_time_control(time_control, ClockScope::Player);
+    }
+
+    /// Times the game under `time_control` with a clock for every player or every team as
+    /// given by `scope`, or makes it untimed if `time_control` is `None`. Every clock starts
+    /// with its full time.
+    ///
+    /// The time a player takes for every action is charged to their clock and passed on to the
+    /// players so that they can budget their thinking time (see `TurnContext::time_budget`). A
+    /// player who takes longer than the time on their clock loses on time on behalf of their
+    /// team, whatever action they chose.
+    pub fn set_time_control(&mut self, time_control: Option<TimeControl>, scope: ClockScope) {
+        self.clock =
+            time_control.map(|control| GameClock::new(control, scope, &self.player_colors));
+        let snapshot = self.clock_snapshot();
+        self.clock_history = vec![snapshot.clone(); self.moves.len()];
+        self.undone_clocks = vec![snapshot; self.undone_moves.len()];
+    }
+
+    /// Returns the time left on every clock, or an empty `Vec` if the game is not timed.
+    fn clock_snapshot(&self) -> Vec<Duration> {
+        self.clock
+            .as_ref()
+            .map(GameClock::snapshot)
+            .unwrap_or_default()
+    }
+
+    /// Sets the time left on every clock to a snapshot taken with `clock_snapshot`.
+    fn restore_clock(&mut self, snapshot: &[Duration]) {
+        if let Some(clock) = &mut self.clock {
+            clock.restore(snapshot);
+        }
+    }
+
+    /// Returns the clocks of the game, or `None` if the game is not timed.
+    pub fn get_clock(&self) -> Option<&GameClock> {
+        self.clock.as_ref()
+    }
+
+    /// Seeds every player with a seed derived from `seed`, in turn order, so that a game
+    /// between the same players can be replayed move for move (see `Player::set_seed`).
+    pub fn set_seed(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        for player in self.players.iter_mut() {
+            player.set_seed(rng.gen());
+        }
+        self.seed = Some(seed);
+    }
+
+    /// Returns the seed given with `set_seed` or `GameBuilder::seed`, or `None` if the game was
+    /// not seeded.
+    pub fn get_seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Sets the file the game is written to with `save` when a player chooses `Action::Save`,
+    /// or `None` to ignore save requests.
+    pub fn set_save_path(&mut self, save_path: Option<PathBuf>) {
+        self.save_path = save_path;
+    }
+
+    /// Attaches an `observer` which is notified of every `GameEvent` from now on.
+    ///
+    /// Games print nothing on their own; attach a `TerminalObserver` to show them in the
+    /// terminal.
+    pub fn add_observer(&mut self, observer: Box<dyn GameObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Passes `event` to every one of `observers` in the order they were attached.
+    ///
+    /// Takes the observers instead of `self` so that events can borrow the rest of the game.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with the first
+    /// `Connect4Error` returned by an observer.
+    fn notify(
+        observers: &mut [Box<dyn GameObserver>],
+        event: &GameEvent,
+    ) -> Result<(), Connect4Error> {
+        for observer in observers.iter_mut() {
+            observer.on_event(event)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the result of the game if it has ended, or `None` otherwise.
+    pub fn get_outcome(&self) -> Option<&GameOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// Returns the column indices of every move played so far in order.
+    pub fn get_moves(&self) -> &[usize] {
+        &self.moves
+    }
+
+    /// Returns the `Board` the game is played on.
+    pub fn get_board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Presents the team of `color` as `team` from now on (see `GameBuilder::team`).
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error::InvalidConfig` if `team` cannot be drawn.
+    pub fn set_team(&mut self, color: Color, team: Team) -> Result<(), Connect4Error> {
+        team.validate()?;
+        self.board.set_team(color, team);
+        Ok(())
+    }
+
+    /// Drops a piece corresponding with the active player's `Color` in the column with index
+    /// `col_index`, records the move along with the time that was left on the clocks at the start
+    /// of the turn, `clock_snapshot`, and passes the turn to the next player.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message if the column is unavailable.
+    fn play(
+        &mut self,
+        col_index: usize,
+        clock_snapshot: Vec<Duration>,
+    ) -> Result<(), Connect4Error> {
+        let color = self.get_current_color();
+        let row_index = self.board.drop_piece(color, col_index)?;
+        self.moves.push(col_index);
+        self.clock_history.push(clock_snapshot);
+        let player_index = self.current_player_index;
+        self.switch_turn();
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::PieceDropped {
+                board: &self.board,
+                color,
+                player_index,
+                row_index,
+                col_index,
+            },
+        )
+    }
+
+    /// Takes back the last move played and returns the turn to the player who made it.
+    ///
+    /// In a timed game the clocks are set back to the time they had at the start of that turn,
+    /// giving back the time spent on the move. Undoing a move of an ended game reopens it so that
+    /// it can be resumed.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message if there are no moves to undo.
+    pub fn undo(&mut self) -> Result<(), Connect4Error> {
+        let col_index = self
+            .moves
+            .pop()
+            .ok_or_else(|| Connect4Error::InvalidState("There are no moves to undo.".into()))?;
+        let row_index = self
+            .board
+            .get_data()
+            .iter()
+            .position(|row| matches!(row.get(col_index), Some(Some(_))))
+            .unwrap_or(0);
+        let color = self.board.undo_piece(col_index)?;
+        self.undone_moves.push(col_index);
+        self.undone_clocks.push(self.clock_snapshot());
+        let clock_snapshot = self.clock_history.pop().unwrap_or_default();
+        self.restore_clock(&clock_snapshot);
+        self.switch_turn_back();
+        self.ended = false;
+        self.outcome = None;
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::PieceRemoved {
+                board: &self.board,
+                color,
+                row_index,
+                col_index,
+            },
+        )
+    }
+
+    /// Replays the last move taken back with `undo`, setting the clocks back to the time they had
+    /// when it was taken back.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message if there are no moves to redo.
+    pub fn redo(&mut self) -> Result<(), Connect4Error> {
+        let col_index = self
+            .undone_moves
+            .pop()
+            .ok_or_else(|| Connect4Error::InvalidState("There are no moves to redo.".into()))?;
+        let clock_snapshot = self.undone_clocks.pop().unwrap_or_default();
+        let undone_moves = std::mem::take(&mut self.undone_moves);
+        self.play(col_index, self.clock_snapshot())?;
+        self.undone_moves = undone_moves;
+        self.restore_clock(&clock_snapshot);
+        Ok(())
+    }
+
+    /// Asks the player at `player_index` for an `Action`, either to take their turn or, if
+    /// `draw_offer` holds the index of the offering player, to respond to a draw offer.
+    ///
+    /// Returns the player's answer along with the time they took to give it. The player is not
+    /// interrupted when their clock runs out; the caller charges the time to the clock only after
+    /// the player answered.
+    fn ask(
+        &mut self,
+        player_index: usize,
+        player_names: &[String],
+        draw_offer: Option<usize>,
+    ) -> (Result<Action, Connect4Error>, Duration) {
+        let ctx = TurnContext {
+            board: &self.board,
+            color: self.player_colors[player_index],
+            amount_to_win: self.amount_to_win,
+            player_colors: &self.player_colors,
+            player_names,
+            player_index,
+            time_left: self
+                .clock
+                .as_ref()
+                .map(|clock| clock.time_left(player_index)),
+            clock: self.clock.as_ref(),
+            draw_offer,
+            draw_declined_by: self
+                .declined_draw_offer
+                .filter(|(offering_index, _)| {
+                    draw_offer.is_none() && *offering_index == player_index
+                })
+                .map(|(_, declining_index)| declining_index),
+        };
+        let start = Instant::now();
+        let action = self.players[player_index].get_action(&ctx);
+        (action, start.elapsed())
+    }
+
+    /// Notifies the observers that the turn changed, prompts the active player for an `Action`
+    /// and applies it to the game.
+    ///
+    /// Dropping a piece plays it for the active player's `Color` and discards any moves that could
+    /// have been redone. Undoing and redoing take back or replay a full round of moves so that the
+    /// turn returns to the requesting player. Saving writes the game to its save path and offering
+    /// a draw asks the other teams to agree to it, after which the player is prompted again unless
+    /// the draw was agreed. Actions that cannot be taken, such as undoing with no full round of
+    /// moves played, are reported in a `GameEvent::ActionRejected` and the player is prompted
+    /// again. A player who gives up with a `Connect4Error::Player` or
+    /// `Connect4Error::StdinClosed` error forfeits the game. In a timed game the time the player
+    /// took is charged to their clock before the action is applied, and a player who ran out of
+    /// time loses instead.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message if the player fails with any other error or
+    /// drops a piece in an unavailable column, or an observer fails.
+    fn take_turn(&mut self) -> Result<(), Connect4Error> {
+        let num_players = self.players.len();
+        let player_index = self.current_player_index;
+        let color = self.get_current_color();
+        let player_names: Vec<String> = self.players.iter().map(|p| p.get_name()).collect();
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::TurnChanged {
+                board: &self.board,
+                color,
+                player_index,
+                player_name: &player_names[player_index],
+                player_names: &player_names,
+                clock: self.clock.as_ref(),
+            },
+        )?;
+        let (action, elapsed) = self.ask(player_index, &player_names, None);
+        let action = match action {
+            Ok(action) => action,
+            Err(error @ (Connect4Error::Player(_) | Connect4Error::StdinClosed)) => {
+                return self.handle_forfeit(player_index, error);
+            }
+            Err(error) => return Err(error),
+        };
+        let clock_snapshot = self.clock_snapshot();
+        if let Some(clock) = &mut self.clock {
+            let dropped = matches!(action, Action::Drop(_));
+            if !clock.charge(player_index, elapsed, dropped) {
+                return self.handle_timeout(player_index);
+            }
+        }
+        match action {
+            Action::Drop(col_index) => {
+                self.play(col_index, clock_snapshot)?;
+                self.undone_moves.clear();
+                self.undone_clocks.clear();
+                self.declined_draw_offer = None;
+            }
+            Action::Undo if self.moves.len() >= num_players => {
+                for _ in 0..num_players {
+                    self.undo()?;
+                }
+            }
+            Action::Redo if self.undone_moves.len() >= num_players => {
+                for _ in 0..num_players {
+                    self.redo()?;
+                }
+            }
+            Action::Undo => self.reject(
+                player_index,
+                &player_names,
+                action,
+                "There is no full round of moves to undo.",
+            )?,
+            Action::Redo => self.reject(
+                player_index,
+                &player_names,
+                action,
+                "There are no undone moves to redo.",
+            )?,
+            Action::Resign => self.handle_resignation(player_index)?,
+            Action::Save => match &self.save_path {
+                Some(save_path) => self.save(save_path)?,
+                None => self.reject(
+                    player_index,
+                    &player_names,
+                    action,
+                    "The game has no save path.",
+                )?,
+            },
+            Action::OfferDraw
+                if self
+                    .declined_draw_offer
+                    .is_none_or(|(offering_index, _)| offering_index != player_index) =>
+            {
+                self.offer_draw(player_index, &player_names)?;
+            }
+            Action::OfferDraw => self.reject(
+                player_index,
+                &player_names,
+                action,
+                "A draw was already declined this turn.",
+            )?,
+            Action::AcceptDraw | Action::DeclineDraw => self.reject(
+                player_index,
+                &player_names,
+                action,
+                "There is no draw offer to respond to.",
+            )?,
+        }
+        Ok(())
+    }
+
+    /// Notifies the observers that the player at `player_index` chose `action`, which cannot be
+    /// taken for `reason`. The game loop then asks the player again.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message if an observer fails.
+    fn reject(
+        &mut self,
+        player_index: usize,
+        player_names: &[String],
+        action: Action,
+        reason: &str,
+    ) -> Result<(), Connect4Error> {
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::ActionRejected {
+                board: &self.board,
+                color: self.player_colors[player_index],
+                player_index,
+                player_name: &player_names[player_index],
+                action,
+                reason,
+            },
+        )
+    }
+
+    /// Offers a draw on behalf of the player at `player_index` to the players of every other
+    /// team in turn order, stopping at the first one who declines.
+    ///
+    /// The game ends in a draw by agreement if all of them accept. A player who gives up while
+    /// responding forfeits the game as in `take_turn`. The time taken to respond is not charged
+    /// to the players' clocks.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message if a player or an observer fails.
+    fn offer_draw(
+        &mut self,
+        player_index: usize,
+        player_names: &[String],
+    ) -> Result<(), Connect4Error> {
+        let num_players = self.players.len();
+        let color = self.player_colors[player_index];
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::DrawOffered {
+                board: &self.board,
+                color,
+                player_index,
+                player_name: &player_names[player_index],
+            },
+        )?;
+        let responders = (1..num_players)
+            .map(|offset| (player_index + offset) % num_players)
+            .filter(|responder| self.player_colors[*responder] != color)
+            .collect::<Vec<usize>>();
+        for responder in responders {
+            match self.ask(responder, player_names, Some(player_index)).0 {
+                Ok(Action::AcceptDraw) => {}
+                Ok(_) => {
+                    self.declined_draw_offer = Some((player_index, responder));
+                    return Self::notify(
+                        &mut self.observers,
+                        &GameEvent::DrawDeclined {
+                            board: &self.board,
+                            color: self.player_colors[responder],
+                            player_index: responder,
+                            player_name: &player_names[responder],
+                        },
+                    );
+                }
+                Err(error @ (Connect4Error::Player(_) | Connect4Error::StdinClosed)) => {
+                    return self.handle_forfeit(responder, error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        self.handle_agreement()
+    }
+
+    /// Returns a `GameOutcome` describing the game ending for `reason` with `winning_color` as
+    /// the winning team.
+    fn build_outcome(&self, reason: EndReason, winning_color: Option<Color>) -> GameOutcome {
+        let winner_indices = winning_color
+            .map(|color| self.get_player_indices_with_color(color))
+            .unwrap_or_default();
+        GameOutcome {
+            reason,
+            winning_color,
+            winner_names: winner_indices
+                .iter()
+                .map(|i| self.get_player(*i).get_name())
+                .collect(),
+            winner_indices,
+            moves: self.moves.clone(),
+            winning_line: match reason {
+                EndReason::Connected => self.board.get_winning_line(self.amount_to_win),
+                _ => None,
+            },
+        }
+    }
+
+    /// Ends the game in a winning condition.
+    ///
+    /// Is passed the winning `Color` to determine behavior.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message.
+    fn handle_win(&mut self, color: Color) -> Result<(), Connect4Error> {
+        self.ended = true;
+        let outcome = self.build_outcome(EndReason::Connected, Some(color));
+        let winning_cells = self.board.get_winning_lines(self.amount_to_win).concat();
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::Won {
+                board: &self.board,
+                outcome: &outcome,
+                winning_cells: &winning_cells,
+            },
+        )?;
+        self.outcome = Some(outcome);
+        Ok(())
+    }
+
+    /// Ends the game in a tied condition (the board being full).
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message.
+    fn handle_tie(&mut self) -> Result<(), Connect4Error> {
+        self.ended = true;
+        let outcome = self.build_outcome(EndReason::BoardFull, None);
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::Tie {
+                board: &self.board,
+                outcome: &outcome,
+            },
+        )?;
+        self.outcome = Some(outcome);
+        Ok(())
+    }
+
+    /// Returns the `Color` that wins when the player at `player_index` resigns, runs out of
+    /// time or forfeits, which is the other team if there is exactly one other team or `None`
+    /// otherwise.
+    fn forfeit_winner(&self, player_index: usize) -> Option<Color> {
+        let resigning_color = self.player_colors[player_index];
+        let mut other_colors: Vec<Color> = Vec::new();
+        for color in self.player_colors.iter() {
+            if *color != resigning_color && !other_colors.contains(color) {
+                other_colors.push(*color);
+            }
+        }
+        match other_colors.as_slice() {
+            [color] => Some(*color),
+            _ => None,
+        }
+    }
+
+    /// Ends the game after the player at `player_index` resigned.
+    ///
+    /// The other team wins if there is exactly one other team, otherwise nobody does.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message.
+    fn handle_resignation(&mut self, player_index: usize) -> Result<(), Connect4Error> {
+        self.ended = true;
+        let resigning_color = self.player_colors[player_index];
+        let winning_color = self.forfeit_winner(player_index);
+        let outcome = self.build_outcome(EndReason::Resignation(player_index), winning_color);
+        let player_name = self.get_player(player_index).get_name();
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::Resigned {
+                board: &self.board,
+                color: resigning_color,
+                player_index,
+                player_name: &player_name,
+                outcome: &outcome,
+            },
+        )?;
+        self.outcome = Some(outcome);
+        Ok(())
+    }
+
+    /// Ends the game after the player at `player_index` ran out of time.
+    ///
+    /// The other team wins if there is exactly one other team, otherwise nobody does.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message.
+    fn handle_timeout(&mut self, player_index: usize) -> Result<(), Connect4Error> {
+        self.ended = true;
+        let color = self.player_colors[player_index];
+        let winning_color = self.forfeit_winner(player_index);
+        let outcome = self.build_outcome(EndReason::Timeout(player_index), winning_color);
+        let player_name = self.get_player(player_index).get_name();
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::TimedOut {
+                board: &self.board,
+                color,
+                player_index,
+                player_name: &player_name,
+                outcome: &outcome,
+            },
+        )?;
+        self.outcome = Some(outcome);
+        Ok(())
+    }
+
+    /// Ends the game in a draw agreed by every team.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message.
+    fn handle_agreement(&mut self) -> Result<(), Connect4Error> {
+        self.ended = true;
+        let outcome = self.build_outcome(EndReason::Agreement, None);
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::Drawn {
+                board: &self.board,
+                outcome: &outcome,
+            },
+        )?;
+        self.outcome = Some(outcome);
+        Ok(())
+    }
+
+    /// Ends the game after the player at `player_index` gave up on their turn with `error`,
+    /// which is passed on to the observers in a `GameEvent::Forfeited`.
+    ///
+    /// The other team wins if there is exactly one other team, otherwise nobody does.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message.
+    fn handle_forfeit(
+        &mut self,
+        player_index: usize,
+        error: Connect4Error,
+    ) -> Result<(), Connect4Error> {
+        self.ended = true;
+        let color = self.player_colors[player_index];
+        let winning_color = self.forfeit_winner(player_index);
+        let outcome = self.build_outcome(EndReason::Forfeit(player_index), winning_color);
+        let player_name = self.get_player(player_index).get_name();
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::Forfeited {
+                board: &self.board,
+                color,
+                player_index,
+                player_name: &player_name,
+                error: &error,
+                outcome: &outcome,
+            },
+        )?;
+        self.outcome = Some(outcome);
+        Ok(())
+    }
+
+    /// Returns a `GameRecord` describing the settings, players and moves of the game so far.
+    pub fn to_record(&self) -> GameRecord {
+        let num_players = self.players.len();
+        GameRecord {
+            num_rows: self.board.num_rows,
+            num_columns: self.board.num_columns,
+            amount_to_win: self.amount_to_win,
+            player_names: self
+                .players
+                .iter()
+                .map(|player| player.get_name())
+                .collect(),
+            player_colors: self.player_colors.clone(),
+            teams: self.board.get_custom_teams(),
+            time_control: self.clock.as_ref().map(GameClock::get_control),
+            clock_scope: self
+                .clock
+                .as_ref()
+                .map_or(ClockScope::Player, GameClock::get_scope),
+            clocks: self.clock_snapshot(),
+            starting_player_index: (self.current_player_index + num_players
+                - self.moves.len() % num_players)
+                % num_players,
+            moves: self.moves.clone(),
+            result: self.outcome.as_ref().map(|outcome| outcome.reason),
+        }
+    }
+
+    /// Writes the game to the file at `path` in the text format of `GameRecord`.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error` containing an error message if the file could not be written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use connect_4::{Color, Game, TerminalPlayer};
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// # let game = Game::builder()
+    /// #     .player(Box::new(TerminalPlayer::new("Player 1".into())), Color::RED)
+    /// #     .player(Box::new(TerminalPlayer::new("Player 2".into())), Color::BLACK)
+    /// #     .build()?;
+    /// game.save("game.c4")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Connect4Error> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_record().to_string()).map_err(|e| {
+            Connect4Error::Io(std::io::Error::new(
+                e.kind(),
+                format!("Could not write {}: {e}", path.display()),
+            ))
+        })
+    }
+
+    /// Constructs a game from a `GameRecord`, replaying its moves with `players` seated in
+    /// place of the recorded players.
+    ///
+    /// The returned game counts as started, so an unfinished game can be continued with
+    /// `resume`. A game recorded as ended is restored as ended with its outcome. The clocks of a
+    /// timed game are set to the recorded time left; undoing a recorded move keeps them as they
+    /// are, since the time spent on it is not recorded.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Game` instance or an `Err` with a
+    /// `Connect4Error` describing why the record cannot be restored.
+    pub fn from_record(
+        record: &GameRecord,
+        players: Vec<Box<dyn Player>>,
+    ) -> Result<Self, Connect4Error> {
+        if players.len() != record.player_colors.len() {
+            return Err(Connect4Error::InvalidConfig(format!(
+                "The record has {} players but {} were given.",
+                record.player_colors.len(),
+                players.len()
+            )));
+        }
+        let mut builder = GameBuilder::new()
+            .board_size(record.num_rows, record.num_columns)
+            .amount_to_win(record.amount_to_win)
+            .players(players, record.player_colors.clone())
+            .starting_player(record.starting_player_index);
+        for (color, team) in record.teams.iter() {
+            builder = builder.team(*color, team.clone());
+        }
+        if let Some(time_control) = record.time_control {
+            builder = builder
+                .time_control(time_control)
+                .clock_scope(record.clock_scope);
+        }
+        let mut game = builder.build()?;
+        game.started = true;
+        for (i, col_index) in record.moves.iter().enumerate() {
+            if game.board.get_winning_color(game.amount_to_win).is_some() {
+                return Err(Connect4Error::Parse(format!(
+                    "Move {} is played after the game was won.",
+                    i + 1
+                )));
+            }
+            game.play(*col_index, Vec::new())
+                .map_err(|e| Connect4Error::Parse(format!("Move {} is invalid: {e}", i + 1)))?;
+        }
+        if !record.clocks.is_empty() {
+            let num_clocks = game
+                .clock
+                .as_ref()
+                .map_or(0, |clock| clock.get_clocks().len());
+            if record.clocks.len() != num_clocks {
+                return Err(Connect4Error::Parse(format!(
+                    "The record has {} clock times but the game has {} clocks.",
+                    record.clocks.len(),
+                    num_clocks
+                )));
+            }
+            game.restore_clock(&record.clocks);
+        }
+        game.clock_history = vec![game.clock_snapshot(); game.moves.len()];
+        let winning_color = game.board.get_winning_color(game.amount_to_win);
+        let outcome = match record.result {
+            None => return Ok(game),
+            Some(EndReason::Connected) => {
+                let color = winning_color.ok_or_else(|| {
+                    Connect4Error::Parse("The record ends in a win but nobody won.".into())
+                })?;
+                game.build_outcome(EndReason::Connected, Some(color))
+            }
+            Some(EndReason::BoardFull) => {
+                if winning_color.is_some() || !game.board.is_full() {
+                    return Err(Connect4Error::Parse(
+                        "The record ends in a tie but the board is not a tie.".into(),
+                    ));
+                }
+                game.build_outcome(EndReason::BoardFull, None)
+            }
+            Some(EndReason::Agreement) => game.build_outcome(EndReason::Agreement, None),
+            Some(
+                reason @ (EndReason::Resignation(player_index)
+                | EndReason::Timeout(player_index)
+                | EndReason::Forfeit(player_index)),
+            ) => {
+                let winning_color = game.forfeit_winner(player_index);
+                game.build_outcome(reason, winning_color)
+            }
+        };
+        game.ended = true;
+        game.outcome = Some(outcome);
+        Ok(game)
+    }
+
+    /// Reads a game saved with `save` from the file at `path`, seating `players` in place of the
+    /// recorded players in turn order.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Game` instance or an `Err` with a
+    /// `Connect4Error` containing an error message if the file could not be read or is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use connect_4::{AIPlayer, Game, Player, TerminalPlayer};
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let players: Vec<Box<dyn Player>> = vec![
+    ///     Box::new(TerminalPlayer::new("Player 1".into())),
+    ///     Box::new(AIPlayer::new("Bot 1".into())),
+    /// ];
+    /// let mut game = Game::load("game.c4", players)?;
+    /// game.resume()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn load<P: AsRef<Path>>(
+        path: P,
+        players: Vec<Box<dyn Player>>,
+    ) -> Result<Self, Connect4Error> {
+        Self::from_record(&GameRecord::load(path)?, players)
+    }
+
+    /// Starts a primary game loop from the current state of the `Game` instance.
+    ///
+    /// This must be called on a game which is neither **unstarted** nor already **ended**.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `GameOutcome` once the game ends or an
+    /// `Err` with a `Connect4Error` containing an error message.
+    pub fn resume(&mut self) -> Result<GameOutcome, Connect4Error> {
+        if !self.started {
+            return Err(Connect4Error::InvalidState(
+                "Attempted to resume an uninstantiated game.".to_string(),
+            ));
+        } else if self.ended {
+            return Err(Connect4Error::InvalidState(
+                "Attempted to resume an ended game.".to_string(),
+            ));
+        }
+        loop {
+            let result = if let Some(color) = self.board.get_winning_color(self.amount_to_win) {
+                self.handle_win(color)
+            } else if self.board.is_full() {
+                self.handle_tie()
+            } else {
+                self.take_turn()
+            };
+            if let Err(error) = result {
+                Self::notify(&mut self.observers, &GameEvent::Error { error: &error })?;
+                break Err(error);
+            }
+            if let Some(outcome) = &self.outcome {
+                break Ok(outcome.clone());
+            }
+        }
+    }
+
+    /// Begins the game and starts a primary game loop.
+    ///
+    /// This must be called on a game which is neither already **started** nor already **ended**.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `GameOutcome` once the game ends or an
+    /// `Err` with a `Connect4Error` containing an error message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::{AIPlayer, Color, Game, Player};
+    ///
+    /// let players: Vec<Box<dyn Player>> = vec![
+    ///     Box::new(AIPlayer::new("Bot 1".into())),
+    ///     Box::new(AIPlayer::new("Bot 2".into())),
+    /// ];
+    /// let player_colors = vec![Color::RED, Color::BLACK];
+    /// let mut game = Game::new(None, players, player_colors).unwrap();
+    ///
+    /// match game.start() {
+    ///     Ok(outcome) => println!("Game ended after {} moves", outcome.move_count()),
+    ///     Err(msg) => eprintln!("[ERROR] {msg}"),
+    /// }
+    /// ```
+    pub fn start(&mut self) -> Result<GameOutcome, Connect4Error> {
+        if self.started {
+            return Err(Connect4Error::InvalidState(
+                "Attempted to start a instantiated game.".to_string(),
+            ));
+        } else if self.ended {
+            return Err(Connect4Error::InvalidState(
+                "Attempted to start an ended game.".to_string(),
+            ));
+        }
+        self.started = true;
+        Self::notify(
+            &mut self.observers,
+            &GameEvent::Started {
+                board: &self.board,
+                player_colors: &self.player_colors,
+                starting_player_index: self.current_player_index,
+            },
+        )?;
+        self.resume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AIPlayer, Budget, Difficulty, MctsPlayer};
+    use std::collections::VecDeque;
+
+    /// A player which takes its scripted actions in order, waiting `delay` before each, and
+    /// gives up once they run out.
+    struct Scripted {
+        name: String,
+        actions: VecDeque<Action>,
+        delay: Duration,
+    }
+
+    impl Player for Scripted {
+        fn get_column_index(&mut self, _ctx: &TurnContext) -> Result<usize, Connect4Error> {
+            Err(Connect4Error::Player(
+                "Scripted players only take actions.".into(),
+            ))
+        }
+
+        fn get_action(&mut self, _ctx: &TurnContext) -> Result<Action, Connect4Error> {
+            std::thread::sleep(self.delay);
+            self.actions
+                .pop_front()
+                .ok_or_else(|| Connect4Error::Player(format!("{} ran out of actions.", self.name)))
+        }
+
+        fn get_name(&self) -> String {
+            self.name.clone()
+        }
+    }
+
+    /// Returns a red and a black player taking their scripted actions.
+    fn scripted(red: Vec<Action>, black: Vec<Action>) -> GameBuilder {
+        Game::builder()
+            .player(
+                Box::new(Scripted {
+                    name: "Red".into(),
+                    actions: red.into(),
+                    delay: Duration::ZERO,
+                }),
+                Color::RED,
+            )
+            .player(
+                Box::new(Scripted {
+                    name: "Black".into(),
+                    actions: black.into(),
+                    delay: Duration::ZERO,
+                }),
+                Color::BLACK,
+            )
+    }
+
+    /// Records the name of every event of a game.
+    struct EventLog(std::rc::Rc<std::cell::RefCell<Vec<String>>>);
+
+    impl GameObserver for EventLog {
+        fn on_event(&mut self, event: &GameEvent) -> Result<(), Connect4Error> {
+            let name = format!("{event:?}");
+            let name = name.split([' ', '{']).next().unwrap_or_default();
+            self.0.borrow_mut().push(name.to_string());
+            Ok(())
+        }
+    }
+
+    /// Starts the game built by `builder`, returning the result of `Game::start` and the names
+    /// of the events it went through.
+    fn run(builder: GameBuilder) -> (Result<GameOutcome, Connect4Error>, Vec<String>) {
+        let events = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut game = builder
+            .observer(Box::new(EventLog(events.clone())))
+            .build()
+            .unwrap();
+        let result = game.start();
+        assert_eq!(game.get_outcome(), result.as_ref().ok());
+        let events = events.borrow().clone();
+        (result, events)
+    }
+
+    /// Plays the game built by `builder` to the end, returning its outcome and event names.
+    fn play_out(builder: GameBuilder) -> (GameOutcome, Vec<String>) {
+        let (result, events) = run(builder);
+        (result.unwrap(), events)
+    }
+
+    fn drops(col_indices: &[usize]) -> Vec<Action> {
+        col_indices.iter().copied().map(Action::Drop).collect()
+    }
+
+    #[test]
+    fn connecting_wins() {
+        let (outcome, events) = play_out(scripted(drops(&[0, 0, 0, 0]), drops(&[1, 1, 1])));
+        assert_eq!(outcome.reason, EndReason::Connected);
+        assert_eq!(outcome.winning_color, Some(Color::RED));
+        assert_eq!(outcome.winner_indices, [0]);
+        assert_eq!(outcome.winner_names, ["Red"]);
+        assert_eq!(outcome.move_count(), 7);
+        assert_eq!(outcome.winning_line.unwrap().len(), 4);
+        assert_eq!(events.last().unwrap(), "Won");
+    }
+
+    #[test]
+    fn any_number_of_teams_can_play() {
+        let builder = scripted(drops(&[0, 3, 5, 0]), drops(&[1, 4, 6, 1])).player(
+            Box::new(Scripted {
+                name: "Yellow".into(),
+                actions: drops(&[2, 2, 2, 2]).into(),
+                delay: Duration::ZERO,
+            }),
+            Color::YELLOW,
+        );
+        let (outcome, _) = play_out(builder);
+        assert_eq!(outcome.reason, EndReason::Connected);
+        assert_eq!(outcome.winning_color, Some(Color::YELLOW));
+        assert_eq!(outcome.winner_indices, [2]);
+        assert_eq!(outcome.move_count(), 12);
+    }
+
+    #[test]
+    fn full_boards_are_ties() {
+        let builder = scripted(drops(&[0, 2, 1]), drops(&[1, 0, 2]))
+            .board_size(2, 3)
+            .amount_to_win(3);
+        let (outcome, events) = play_out(builder);
+        assert_eq!(outcome.reason, EndReason::BoardFull);
+        assert!(outcome.is_tie());
+        assert_eq!(outcome.winning_line, None);
+        assert_eq!(events.last().unwrap(), "Tie");
+    }
+
+    #[test]
+    fn resigning_loses() {
+        let (outcome, events) = play_out(scripted(vec![Action::Drop(3)], vec![Action::Resign]));
+        assert_eq!(outcome.reason, EndReason::Resignation(1));
+        assert_eq!(outcome.winning_color, Some(Color::RED));
+        assert_eq!(outcome.moves, [3]);
+        assert_eq!(events.last().unwrap(), "Resigned");
+    }
+
+    #[test]
+    fn running_out_of_time_loses() {
+        let slow = Scripted {
+            name: "Slow".into(),
+            actions: drops(&[1]).into(),
+            delay: Duration::from_millis(60),
+        };
+        let builder = Game::builder()
+            .player(
+                Box::new(Scripted {
+                    name: "Red".into(),
+                    actions: drops(&[0]).into(),
+                    delay: Duration::ZERO,
+                }),
+                Color::RED,
+            )
+            .player(Box::new(slow), Color::BLACK)
+            .time_control(TimeControl::PerMove {
+                limit: Duration::from_millis(20),
+            });
+        let (outcome, events) = play_out(builder);
+        assert_eq!(outcome.reason, EndReason::Timeout(1));
+        assert_eq!(outcome.winning_color, Some(Color::RED));
+        assert_eq!(outcome.moves, [0]);
+        assert_eq!(events.last().unwrap(), "TimedOut");
+    }
+
+    #[test]
+    fn clocks_are_charged_for_every_move() {
+        let builder = scripted(drops(&[0, 0]), drops(&[1]))
+            .time_control(TimeControl::Fischer {
+                initial: Duration::from_secs(60),
+                increment: Duration::from_secs(5),
+            })
+            .clock_scope(ClockScope::Team);
+        let mut game = builder.build().unwrap();
+        assert_eq!(game.start().unwrap().reason, EndReason::Forfeit(1));
+        let clock = game.get_clock().unwrap();
+        assert!(clock.time_left(0) > Duration::from_secs(69));
+        assert!(clock.time_left(1) > Duration::from_secs(64));
+    }
+
+    #[test]
+    fn undoing_gives_back_the_time_spent_on_the_moves() {
+        let red = Scripted {
+            name: "Red".into(),
+            actions: vec![Action::Drop(0), Action::Undo, Action::Resign].into(),
+            delay: Duration::from_millis(50),
+        };
+        let builder = Game::builder()
+            .player(Box::new(red), Color::RED)
+            .player(
+                Box::new(Scripted {
+                    name: "Black".into(),
+                    actions: drops(&[1]).into(),
+                    delay: Duration::from_millis(50),
+                }),
+                Color::BLACK,
+            )
+            .time_control(TimeControl::SuddenDeath {
+                initial: Duration::from_secs(60),
+            });
+        let mut game = builder.build().unwrap();
+        assert_eq!(game.start().unwrap().reason, EndReason::Resignation(0));
+        let clock = game.get_clock().unwrap();
+        // only the resignation is charged to red once both moves are taken back
+        assert!(clock.time_left(0) > Duration::from_millis(59_900));
+        assert_eq!(clock.time_left(1), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn player_errors_forfeit_the_game() {
+        let (outcome, events) = play_out(scripted(drops(&[3]), vec![]));
+        assert_eq!(outcome.reason, EndReason::Forfeit(1));
+        assert_eq!(outcome.winning_color, Some(Color::RED));
+        assert_eq!(events[events.len() - 2..], ["TurnChanged", "Forfeited"]);
+    }
+
+    #[test]
+    fn unavailable_columns_stop_the_game() {
+        let (result, _) = run(scripted(drops(&[0, 0, 0, 7]), drops(&[0, 0, 0])));
+        assert!(matches!(
+            result,
+            Err(Connect4Error::ColumnOutOfRange { col_index: 7, .. })
+        ));
+        let (result, _) = run(scripted(drops(&[0, 0, 0, 0]), drops(&[0, 0, 0])));
+        assert!(matches!(
+            result,
+            Err(Connect4Error::ColumnFull { col_index: 0 })
+        ));
+    }
+
+    #[test]
+    fn agreed_draws_end_the_game() {
+        let (outcome, events) = play_out(scripted(
+            vec![Action::Drop(3), Action::OfferDraw],
+            vec![Action::Drop(3), Action::AcceptDraw],
+        ));
+        assert_eq!(outcome.reason, EndReason::Agreement);
+        assert!(outcome.is_tie());
+        assert_eq!(outcome.winning_color, None);
+        assert_eq!(outcome.moves, [3, 3]);
+        assert_eq!(events[events.len() - 2..], ["DrawOffered", "Drawn"]);
+    }
+
+    /// A scripted player which records `TurnContext::draw_declined_by` whenever it is asked.
+    struct DeclineLog(
+        Scripted,
+        std::rc::Rc<std::cell::RefCell<Vec<Option<usize>>>>,
+    );
+
+    impl Player for DeclineLog {
+        fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+            self.0.get_column_index(ctx)
+        }
+
+        fn get_action(&mut self, ctx: &TurnContext) -> Result<Action, Connect4Error> {
+            self.1.borrow_mut().push(ctx.draw_declined_by);
+            self.0.get_action(ctx)
+        }
+
+        fn get_name(&self) -> String {
+            self.0.get_name()
+        }
+    }
+
+    #[test]
+    fn declined_draws_are_reported_until_a_piece_is_dropped() {
+        let declines = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let red = Scripted {
+            name: "Red".into(),
+            actions: vec![
+                Action::OfferDraw,
+                Action::Drop(0),
+                Action::OfferDraw,
+                Action::Resign,
+            ]
+            .into(),
+            delay: Duration::ZERO,
+        };
+        let black = Scripted {
+            name: "Black".into(),
+            actions: vec![Action::DeclineDraw, Action::Drop(1), Action::DeclineDraw].into(),
+            delay: Duration::ZERO,
+        };
+        let builder = Game::builder()
+            .player(Box::new(DeclineLog(red, declines.clone())), Color::RED)
+            .player(Box::new(black), Color::BLACK);
+        let (outcome, _) = play_out(builder);
+        assert_eq!(outcome.reason, EndReason::Resignation(0));
+        assert_eq!(*declines.borrow(), [None, Some(1), None, Some(1)]);
+    }
+
+    #[test]
+    fn declined_draws_cannot_be_offered_again_in_the_same_turn() {
+        let (outcome, events) = play_out(scripted(
+            vec![Action::OfferDraw, Action::OfferDraw, Action::Resign],
+            vec![Action::DeclineDraw],
+        ));
+        assert_eq!(outcome.reason, EndReason::Resignation(0));
+        assert_eq!(events.iter().filter(|e| *e == "DrawOffered").count(), 1);
+        assert_eq!(events.iter().filter(|e| *e == "DrawDeclined").count(), 1);
+        assert_eq!(events.iter().filter(|e| *e == "ActionRejected").count(), 1);
+    }
+
+    #[test]
+    fn actions_that_cannot_be_taken_are_rejected() {
+        let (outcome, events) = play_out(scripted(
+            vec![
+                Action::Undo,
+                Action::Redo,
+                Action::Save,
+                Action::AcceptDraw,
+                Action::DeclineDraw,
+                Action::Drop(3),
+            ],
+            vec![Action::Resign],
+        ));
+        assert_eq!(outcome.reason, EndReason::Resignation(1));
+        assert_eq!(outcome.moves, [3]);
+        assert_eq!(
+            events[..12],
+            [
+                "Started",
+                "TurnChanged",
+                "ActionRejected",
+                "TurnChanged",
+                "ActionRejected",
+                "TurnChanged",
+                "ActionRejected",
+                "TurnChanged",
+                "ActionRejected",
+                "TurnChanged",
+                "ActionRejected",
+                "TurnChanged",
+            ]
+        );
+    }
+
+    #[test]
+    fn observers_see_the_events_in_order() {
+        let builder = scripted(
+            vec![Action::Drop(3), Action::Undo, Action::Resign],
+            drops(&[4]),
+        );
+        let (_, events) = play_out(builder);
+        assert_eq!(
+            events,
+            [
+                "Started",
+                "TurnChanged",
+                "PieceDropped",
+                "TurnChanged",
+                "PieceDropped",
+                "TurnChanged",
+                "PieceRemoved",
+                "PieceRemoved",
+                "TurnChanged",
+                "Resigned",
+            ]
+        );
+    }
+
+    /// Plays a game between seeded random players and returns its moves.
+    fn seeded_moves(seed: u64) -> Vec<usize> {
+        let mut game = Game::builder()
+            .player(
+                Box::new(AIPlayer::with_difficulty("Easy".into(), Difficulty::Easy)),
+                Color::RED,
+            )
+            .player(
+                Box::new(MctsPlayer::new("MCTS".into(), Budget::Iterations(50))),
+                Color::BLACK,
+            )
+            .seed(seed)
+            .build()
+            .unwrap();
+        assert_eq!(game.get_seed(), Some(seed));
+        game.start().unwrap().moves
+    }
+
+    #[test]
+    fn seeded_games_replay_the_same_moves() {
+        let moves = seeded_moves(42);
+        assert_eq!(seeded_moves(42), moves);
+        assert!((0..5).any(|seed| seeded_moves(seed) != moves));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{Action, Board, Color, Connect4Error, GameClock, GameOutcome};
+
+/// Something that happened in a `Game`, as passed to every `GameObserver` of the game.
+///
+/// Every event carries the `Board` as it is right after the event.
+#[derive(Debug, Clone, Copy)]
+pub enum GameEvent<'a> {
+    /// The game was started with `Game::start`.
+    Started {
+        board: &'a Board,
+        /// The color team of every player in turn order.
+        player_colors: &'a [Color],
+        /// The index of the player who moves first.
+        starting_player_index: usize,
+    },
+    /// A piece was dropped in the cell at `(row_index, col_index)`.
+    PieceDropped {
+        board: &'a Board,
+        color: Color,
+        /// The index of the player the piece was dropped for.
+        player_index: usize,
+        row_index: usize,
+        col_index: usize,
+    },
+    /// The piece in the cell at `(row_index, col_index)` was taken back with `Game::undo`.
+    PieceRemoved {
+        board: &'a Board,
+        color: Color,
+        row_index: usize,
+        col_index: usize,
+    },
+    /// The player at `player_index` is about to be asked for their move.
+    TurnChanged {
+        board: &'a Board,
+        color: Color,
+        player_index: usize,
+        player_name: &'a str,
+        /// The name of every player in turn order.
+        player_names: &'a [String],
+        /// The clocks of the game as the turn starts, or `None` if the game is not timed.
+        clock: Option<&'a GameClock>,
+    },
+    /// A team connected the required amount of pieces in a row.
+    Won {
+        board: &'a Board,
+        outcome: &'a GameOutcome,
+        /// Every cell of every winning run, as a single move can complete more than one.
+        winning_cells: &'a [(usize, usize)],
+    },
+    /// The board filled up without any team winning.
+    Tie {
+        board: &'a Board,
+        outcome: &'a GameOutcome,
+    },
+    /// The player at `player_index` resigned on behalf of their team.
+    Resigned {
+        board: &'a Board,
+        color: Color,
+        player_index: usize,
+        player_name: &'a str,
+        outcome: &'a GameOutcome,
+    },
+    /// The player at `player_index` ran out of time on their clock, losing on behalf of their
+    /// team.
+    TimedOut {
+        board: &'a Board,
+        color: Color,
+        player_index: usize,
+        player_name: &'a str,
+        outcome: &'a GameOutcome,
+    },
+    /// The player at `player_index` offered a draw, which the players of the other teams are
+    /// asked to accept.
+    DrawOffered {
+        board: &'a Board,
+        color: Color,
+        player_index: usize,
+        player_name: &'a str,
+    },
+    /// The player at `player_index` declined the draw offer, which the game continues after.
+    DrawDeclined {
+        board: &'a Board,
+        color: Color,
+        player_index: usize,
+        player_name: &'a str,
+    },
+    /// The player at `player_index` chose `action`, which cannot be taken at this point of the
+    /// game for `reason`, such as an `Action::Undo` with no moves to take back. The player is
+    /// asked again.
+    ActionRejected {
+        board: &'a Board,
+        color: Color,
+        player_index: usize,
+        player_name: &'a str,
+        action: Action,
+        reason: &'a str,
+    },
+    /// Every team agreed to a draw.
+    Drawn {
+        board: &'a Board,
+        outcome: &'a GameOutcome,
+    },
+    /// The player at `player_index` gave up on their turn with `error`, a
+    /// `Connect4Error::Player` or `Connect4Error::StdinClosed`, forfeiting on behalf of their
+    /// team.
+    Forfeited {
+        board: &'a Board,
+        color: Color,
+        player_index: usize,
+        player_name: &'a str,
+        error: &'a Connect4Error,
+        outcome: &'a GameOutcome,
+    },
+    /// A player or the game failed with `error` and the game loop stops with it. Players who give
+    /// up are reported with `GameEvent::Forfeited` instead.
+    Error { error: &'a Connect4Error },
+}
+
+/// Receives the events of a `Game` as they happen, which lets user interfaces, loggers and
+/// spectators follow a game without the game knowing about them.
+///
+/// Observers are attached with `GameBuilder::observer` or `Game::add_observer`. Games print
+/// nothing unless an observer does, such as the built-in `TerminalObserver`.
+///
+/// # Examples
+///
+/// ```
+/// use connect_4::{AIPlayer, Color, Connect4Error, Game, GameEvent, GameObserver};
+///
+/// /// Prints every move played.
+/// struct MoveLogger;
+///
+/// impl GameObserver for MoveLogger {
+///     fn on_event(&mut self, event: &GameEvent) -> Result<(), Connect4Error> {
+///         if let GameEvent::PieceDropped { color, col_index, .. } = event {
+///             println!("{color} plays column {}", col_index + 1);
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Connect4Error> {
+/// let mut game = Game::builder()
+///     .player(Box::new(AIPlayer::new("Bot 1".into())), Color::RED)
+///     .player(Box::new(AIPlayer::new("Bot 2".into())), Color::BLACK)
+///     .observer(Box::new(MoveLogger))
+///     .build()?;
+/// game.start()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait GameObserver {
+    /// Handles an `event` of the game the observer is attached to.
+    ///
+    /// Returns a `Result` type with a unit `Ok` to indicate a success or an `Err` with a
+    /// `Connect4Error`, which stops the game loop.
+    fn on_event(&mut self, event: &GameEvent) -> Result<(), Connect4Error>;
+}
@@ -0,0 +1,151 @@
+use crate::{Color, Connect4Error};
+
+/// Describes why a game ended.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EndReason {
+    /// A team got the required amount of pieces in a row.
+    Connected,
+    /// The board filled up without any team winning.
+    BoardFull,
+    /// The player at the given index resigned on behalf of their team.
+    Resignation(usize),
+    /// The player at the given index ran out of time on their clock, losing on behalf of their
+    /// team.
+    Timeout(usize),
+    /// Every team agreed to a draw offered by one of the players.
+    Agreement,
+    /// The player at the given index gave up on their turn by failing with a
+    /// `Connect4Error::Player` error, such as quitting, or `Connect4Error::StdinClosed`,
+    /// forfeiting on behalf of their team.
+    Forfeit(usize),
+}
+
+impl EndReason {
+    /// Returns `true` if the game ended in a tie, on a full board or by agreement.
+    ///
+    /// A game lost by resignation, time or forfeit is no tie even if no single team was left
+    /// to win it.
+    pub fn is_tie(&self) -> bool {
+        matches!(self, EndReason::BoardFull | EndReason::Agreement)
+    }
+
+    /// Returns the index of the player whose action ended the game on behalf of their team, or
+    /// `None` if the game was decided on the board or by agreement.
+    pub fn player_index(&self) -> Option<usize> {
+        match *self {
+            EndReason::Resignation(player_index)
+            | EndReason::Timeout(player_index)
+            | EndReason::Forfeit(player_index) => Some(player_index),
+            EndReason::Connected | EndReason::BoardFull | EndReason::Agreement => None,
+        }
+    }
+}
+
+/// Formats the reason as used by `GameRecord` and `SimulationReport::games_csv`: `connect`,
+/// `tie`, `draw`, or `resign <n>`, `time <n>` or `forfeit <n>` with the 1-based number `n` of
+/// the player who ended the game.
+impl std::fmt::Display for EndReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            EndReason::Connected => write!(f, "connect"),
+            EndReason::BoardFull => write!(f, "tie"),
+            EndReason::Agreement => write!(f, "draw"),
+            EndReason::Resignation(player_index) => write!(f, "resign {}", player_index + 1),
+            EndReason::Timeout(player_index) => write!(f, "time {}", player_index + 1),
+            EndReason::Forfeit(player_index) => write!(f, "forfeit {}", player_index + 1),
+        }
+    }
+}
+
+/// Parses a reason from the form produced by `Display`.
+impl std::str::FromStr for EndReason {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Connect4Error::Parse(format!("Invalid result \"{}\".", s.trim()));
+        match s.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["connect"] => Ok(EndReason::Connected),
+            ["tie"] => Ok(EndReason::BoardFull),
+            ["draw"] => Ok(EndReason::Agreement),
+            [kind, player] => {
+                let player_index = match player.parse::<usize>() {
+                    Ok(0) => return Err(Connect4Error::Parse("Player numbers start at 1.".into())),
+                    Ok(player) => player - 1,
+                    Err(_) => return Err(invalid()),
+                };
+                match kind {
+                    "resign" => Ok(EndReason::Resignation(player_index)),
+                    "time" => Ok(EndReason::Timeout(player_index)),
+                    "forfeit" => Ok(EndReason::Forfeit(player_index)),
+                    _ => Err(invalid()),
+                }
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// The result of an ended game as returned by `Game::start` and `Game::resume`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameOutcome {
+    /// Why the game ended.
+    pub reason: EndReason,
+    /// The `Color` of the winning team, or `None` if nobody won.
+    pub winning_color: Option<Color>,
+    /// The indices of all players on the winning team.
+    pub winner_indices: Vec<usize>,
+    /// The names of all players on the winning team, in the same order as `winner_indices`.
+    pub winner_names: Vec<String>,
+    /// The column indices of every move played in the game in order.
+    pub moves: Vec<usize>,
+    /// The `(row_index, col_index)` cells of the winning run if the game was won by connecting
+    /// pieces.
+    pub winning_line: Option<Vec<(usize, usize)>>,
+}
+
+impl GameOutcome {
+    /// Returns `true` if the game ended in a tie, on a full board or by agreement (see
+    /// `EndReason::is_tie`).
+    pub fn is_tie(&self) -> bool {
+        self.reason.is_tie()
+    }
+
+    /// Returns the number of moves played in the game.
+    pub fn move_count(&self) -> usize {
+        self.moves.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reasons_round_trip_through_text() {
+        for (text, reason) in [
+            ("connect", EndReason::Connected),
+            ("tie", EndReason::BoardFull),
+            ("draw", EndReason::Agreement),
+            ("resign 1", EndReason::Resignation(0)),
+            ("time 2", EndReason::Timeout(1)),
+            ("forfeit 3", EndReason::Forfeit(2)),
+        ] {
+            assert_eq!(reason.to_string(), text);
+            assert_eq!(text.parse::<EndReason>().unwrap(), reason);
+        }
+        assert_eq!(EndReason::Forfeit(2).player_index(), Some(2));
+        assert_eq!(EndReason::Agreement.player_index(), None);
+        assert!(EndReason::BoardFull.is_tie() && EndReason::Agreement.is_tie());
+        assert!(!EndReason::Resignation(0).is_tie() && !EndReason::Connected.is_tie());
+        for text in [
+            "", "won", "resign", "resign 0", "resign x", "quit 1", "tie 1",
+        ] {
+            assert!(
+                matches!(text.parse::<EndReason>(), Err(Connect4Error::Parse(_))),
+                "{text:?}"
+            );
+        }
+    }
+}
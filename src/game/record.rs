@@ -0,0 +1,627 @@
+use crate::{
+    format_moves, parse_moves, Board, ClockScope, Color, Connect4Error, EndReason, Team,
+    TimeControl,
+};
+use std::path::Path;
+use std::time::Duration;
+
+/// A portable description of a game which can be written to and read from text.
+///
+/// The text format is a header of `key: value` lines followed by the moves:
+///
+/// ```text
+/// rows: 6
+/// columns: 7
+/// win: 4
+/// player: Red Player 1
+/// player: Black Bot 1
+/// team: Red Dragons,D,#ff6600
+/// time: fischer:300+5
+/// clock: team
+/// clocks: 287.5 301.2
+/// start: 1
+/// result: connect
+/// moves: 4453443
+/// ```
+///
+/// Each `player` line holds the player's color followed by their name, in turn order. Each
+/// optional `team` line holds a color followed by its custom presentation in the notation of
+/// `Team`'s `Display` implementation. The optional `time` line holds the `TimeControl` of a
+/// timed game and the optional `clock` line whether its clocks belong to each `player` (the
+/// default) or each `team`. The optional `clocks` line holds the seconds left on every clock
+/// in the order of `GameClock::get_clocks`; without it every clock starts full. `start` is
+/// the 1-based number of the player who moved first. `result` is `*` for an unfinished game or
+/// the `Display` form of the `EndReason`: `connect` or `tie` for a game decided on the board,
+/// `draw` for a draw by agreement, or `resign <n>`, `time <n>` or `forfeit <n>` if the player
+/// with the 1-based number `n` resigned, ran out of time or forfeited. `moves` uses the notation
+/// of `format_moves`. Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRecord {
+    /// The number of rows in the board.
+    pub num_rows: usize,
+    /// The number of columns in the board.
+    pub num_columns: usize,
+    /// The number of pieces a team must get in a row to win the game.
+    pub amount_to_win: usize,
+    /// The names of the players in turn order.
+    pub player_names: Vec<String>,
+    /// The color team of each player in `player_names`.
+    pub player_colors: Vec<Color>,
+    /// The custom presentation of every team which has one (see `GameBuilder::team`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub teams: Vec<(Color, Team)>,
+    /// The time control of the game, or `None` if it is untimed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub time_control: Option<TimeControl>,
+    /// Whether every player or every team has a clock of their own in a timed game.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clock_scope: ClockScope,
+    /// The time left on every clock of a timed game in the order of `GameClock::get_clocks`, or
+    /// an empty `Vec` if every clock has its full time.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clocks: Vec<Duration>,
+    /// The index of the player who made the first move.
+    pub starting_player_index: usize,
+    /// The column indices of every move played in order.
+    pub moves: Vec<usize>,
+    /// Why the game ended, or `None` if it has not ended yet.
+    pub result: Option<EndReason>,
+}
+
+impl GameRecord {
+    /// Returns the position reached by the recorded moves, on a board of the recorded size with
+    /// the pieces of each move in the color of the player who made it and the recorded teams.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `Board` or an `Err` with a
+    /// `Connect4Error::Parse` if the board has no rows or no columns, there are no players, the
+    /// starting player is not one of them, or a move does not fit on the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::GameRecord;
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let record: GameRecord = "rows: 4\ncolumns: 5\nplayer: Red A\nplayer: Yellow B\n\
+    ///                           player: Green C\nstart: 2\nmoves: 123"
+    ///     .parse()?;
+    /// assert_eq!(record.to_board()?.to_grid_str(), ".....\n.....\n.....\nYGR..");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_board(&self) -> Result<Board, Connect4Error> {
+        if self.num_rows == 0 || self.num_columns == 0 {
+            return Err(Connect4Error::Parse(format!(
+                "The board must have at least one row and one column (got {}x{}).",
+                self.num_rows, self.num_columns
+            )));
+        }
+        let num_players = self.player_colors.len();
+        if self.starting_player_index >= num_players {
+            return Err(Connect4Error::Parse(format!(
+                "The starting player {} is not one of the {} players.",
+                self.starting_player_index + 1,
+                num_players
+            )));
+        }
+        let mut board = Board::new(self.num_rows, self.num_columns, 3, 7);
+        for (color, team) in self.teams.iter() {
+            board.set_team(*color, team.clone());
+        }
+        for (i, col_index) in self.moves.iter().enumerate() {
+            let color = self.player_colors[(self.starting_player_index + i) % num_players];
+            board.drop_piece(color, *col_index).map_err(|e| {
+                Connect4Error::Parse(format!("Move {} does not fit on the board: {e}", i + 1))
+            })?;
+        }
+        Ok(board)
+    }
+
+    /// Reads a record in the text format from the file at `path`, such as one written by
+    /// `Game::save`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `GameRecord` or an `Err` with a
+    /// `Connect4Error` containing an error message if the file could not be read or is invalid.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Connect4Error> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            Connect4Error::Io(std::io::Error::new(
+                e.kind(),
+                format!("Could not read {}: {e}", path.display()),
+            ))
+        })?;
+        text.parse()
+            .map_err(|e| Connect4Error::Parse(format!("Invalid game file {}: {e}", path.display())))
+    }
+}
+
+impl std::fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "rows: {}", self.num_rows)?;
+        writeln!(f, "columns: {}", self.num_columns)?;
+        writeln!(f, "win: {}", self.amount_to_win)?;
+        for (name, color) in self.player_names.iter().zip(self.player_colors.iter()) {
+            writeln!(f, "player: {} {}", color, name)?;
+        }
+        for (color, team) in self.teams.iter() {
+            writeln!(f, "team: {} {}", color, team)?;
+        }
+        if let Some(time_control) = self.time_control {
+            writeln!(f, "time: {}", time_control)?;
+            if self.clock_scope != ClockScope::Player {
+                writeln!(f, "clock: {}", self.clock_scope)?;
+            }
+            if !self.clocks.is_empty() {
+                let clocks = self
+                    .clocks
+                    .iter()
+                    .map(|left| {
+                        let millis = left.as_millis().min(u64::MAX as u128) as u64;
+                        Duration::from_millis(millis).as_secs_f64().to_string()
+                    })
+                    .collect::<Vec<String>>();
+                writeln!(f, "clocks: {}", clocks.join(" "))?;
+            }
+        }
+        writeln!(f, "start: {}", self.starting_player_index + 1)?;
+        match self.result {
+            None => writeln!(f, "result: *")?,
+            Some(reason) => writeln!(f, "result: {reason}")?,
+        }
+        writeln!(f, "moves: {}", format_moves(&self.moves, self.num_columns))
+    }
+}
+
+impl std::str::FromStr for GameRecord {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_number = |line_number: usize, key: &str, value: &str| {
+            value.parse::<usize>().map_err(|_| {
+                Connect4Error::Parse(format!("Line {line_number}: invalid {key} \"{value}\"."))
+            })
+        };
+        let mut num_rows = None;
+        let mut num_columns = None;
+        let mut amount_to_win = None;
+        let mut player_names = Vec::new();
+        let mut player_colors = Vec::new();
+        let mut teams = Vec::new();
+        let mut time_control = None;
+        let mut clock_scope = ClockScope::Player;
+        let mut clocks = Vec::new();
+        let mut starting_player = None;
+        let mut result = None;
+        let mut moves_str = None;
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                Connect4Error::Parse(format!("Line {line_number}: expected \"key: value\"."))
+            })?;
+            let value = value.trim();
+            match key.trim() {
+                "rows" => num_rows = Some(parse_number(line_number, "row count", value)?),
+                "columns" => num_columns = Some(parse_number(line_number, "column count", value)?),
+                "win" => amount_to_win = Some(parse_number(line_number, "win length", value)?),
+                "player" => {
+                    let (color, name) = value.split_once(' ').unwrap_or((value, ""));
+                    let color = color
+                        .parse::<Color>()
+                        .map_err(|e| Connect4Error::Parse(format!("Line {line_number}: {e}")))?;
+                    player_colors.push(color);
+                    player_names.push(name.trim().to_string());
+                }
+                "team" => {
+                    let (color, team) = value.split_once(' ').unwrap_or((value, ""));
+                    let parse_error =
+                        |e: Connect4Error| Connect4Error::Parse(format!("Line {line_number}: {e}"));
+                    teams.push((
+                        color.parse::<Color>().map_err(parse_error)?,
+                        team.parse::<Team>().map_err(parse_error)?,
+                    ));
+                }
+                "time" => {
+                    time_control =
+                        Some(value.parse::<TimeControl>().map_err(|e| {
+                            Connect4Error::Parse(format!("Line {line_number}: {e}"))
+                        })?)
+                }
+                "clock" => {
+                    clock_scope = value
+                        .parse::<ClockScope>()
+                        .map_err(|e| Connect4Error::Parse(format!("Line {line_number}: {e}")))?
+                }
+                "clocks" => {
+                    clocks = value
+                        .split_whitespace()
+                        .map(|secs| {
+                            secs.parse::<f64>()
+                                .ok()
+                                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                                .ok_or_else(|| {
+                                    Connect4Error::Parse(format!(
+                                        "Line {line_number}: invalid clock time \"{secs}\"."
+                                    ))
+                                })
+                        })
+                        .collect::<Result<Vec<Duration>, Connect4Error>>()?
+                }
+                "start" => {
+                    starting_player = Some(parse_number(line_number, "starting player", value)?)
+                }
+                "result" => {
+                    result = Some(match value {
+                        "*" => None,
+                        value => Some(value.parse::<EndReason>().map_err(|e| {
+                            Connect4Error::Parse(format!("Line {line_number}: {e}"))
+                        })?),
+                    })
+                }
+                "moves" => moves_str = Some(value.to_string()),
+                key => {
+                    return Err(Connect4Error::Parse(format!(
+                        "Line {line_number}: unknown key \"{key}\"."
+                    )))
+                }
+            }
+        }
+        let num_columns =
+            num_columns.ok_or_else(|| Connect4Error::Parse("Missing \"columns\" line.".into()))?;
+        let starting_player = starting_player.unwrap_or(1);
+        if starting_player == 0 || starting_player > player_colors.len() {
+            return Err(Connect4Error::Parse(format!(
+                "The starting player {} is not one of the {} players.",
+                starting_player,
+                player_colors.len()
+            )));
+        }
+        if let Some(index) = result.flatten().and_then(|reason| reason.player_index()) {
+            if index >= player_colors.len() {
+                return Err(Connect4Error::Parse(format!(
+                    "The losing player {} is not one of the {} players.",
+                    index + 1,
+                    player_colors.len()
+                )));
+            }
+        }
+        Ok(GameRecord {
+            num_rows: num_rows
+                .ok_or_else(|| Connect4Error::Parse("Missing \"rows\" line.".into()))?,
+            num_columns,
+            amount_to_win: amount_to_win.unwrap_or(4),
+            player_names,
+            player_colors,
+            teams,
+            time_control,
+            clock_scope,
+            clocks,
+            starting_player_index: starting_player - 1,
+            moves: parse_moves(moves_str.as_deref().unwrap_or(""), num_columns)?,
+            result: result.flatten(),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl GameRecord {
+    /// Returns a `Result` type with an `Ok` containing the record as pretty printed JSON or an
+    /// `Err` with a `Connect4Error::InvalidState` if the record cannot be serialized.
+    pub fn to_json(&self) -> Result<String, Connect4Error> {
+        serde_json::to_string_pretty(self).map_err(|e| {
+            Connect4Error::InvalidState(format!("Could not write the game record as JSON: {e}"))
+        })
+    }
+
+    /// Parses a record from the JSON produced by `GameRecord::to_json`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `GameRecord` or an `Err` with a
+    /// `Connect4Error::Parse` describing why `json` is not a valid record.
+    pub fn from_json(json: &str) -> Result<Self, Connect4Error> {
+        serde_json::from_str(json)
+            .map_err(|e| Connect4Error::Parse(format!("Invalid game record JSON: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AIPlayer, Game, Player};
+
+    const TEXT: &str = "rows: 6
+columns: 7
+win: 4
+player: Red Player 1
+player: Black Bot 1
+team: Red Dragons,D,#ff6600
+start: 2
+result: resign 1
+moves: 4453443
+";
+
+    /// Returns the message of a `Connect4Error::Parse`, panicking on anything else.
+    fn parse_error(result: Result<GameRecord, Connect4Error>) -> String {
+        match result {
+            Err(Connect4Error::Parse(message)) => message,
+            result => panic!("expected a parse error, got {result:?}"),
+        }
+    }
+
+    /// Returns random players to seat in place of the `num_players` recorded ones.
+    fn players(num_players: usize) -> Vec<Box<dyn Player>> {
+        (0..num_players)
+            .map(|i| Box::new(AIPlayer::new(format!("Bot {}", i + 1))) as Box<dyn Player>)
+            .collect()
+    }
+
+    #[test]
+    fn text_round_trips() {
+        let record: GameRecord = TEXT.parse().unwrap();
+        assert_eq!(record.player_names, ["Player 1", "Bot 1"]);
+        assert_eq!(record.player_colors, [Color::RED, Color::BLACK]);
+        assert_eq!(
+            record.teams,
+            [(Color::RED, Team::new("Dragons".into(), 'D', (255, 102, 0)))]
+        );
+        assert_eq!(record.starting_player_index, 1);
+        assert_eq!(record.moves, [3, 3, 4, 2, 3, 3, 2]);
+        assert_eq!(record.result, Some(EndReason::Resignation(0)));
+        assert_eq!(record.to_string(), TEXT);
+        assert_eq!(record.to_string().parse::<GameRecord>().unwrap(), record);
+        for (result, reason) in [
+            ("draw", EndReason::Agreement),
+            ("forfeit 2", EndReason::Forfeit(1)),
+        ] {
+            let text = TEXT.replace("resign 1", result);
+            let record: GameRecord = text.parse().unwrap();
+            assert_eq!(record.result, Some(reason));
+            assert_eq!(record.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn defaults_and_comments() {
+        let record: GameRecord = "# a comment\n\nrows: 4\ncolumns: 12\nplayer: Red A\n\
+                                  player: Black B\nmoves: 1 12 10"
+            .parse()
+            .unwrap();
+        assert_eq!(record.amount_to_win, 4);
+        assert_eq!(record.starting_player_index, 0);
+        assert_eq!(record.result, None);
+        assert_eq!(record.moves, [0, 11, 9]);
+        assert!(record.to_string().contains("result: *\nmoves: 1 12 10\n"));
+    }
+
+    #[test]
+    fn invalid_text_is_rejected() {
+        let header = "rows: 6\ncolumns: 7\nplayer: Red A\nplayer: Black B\n";
+        for (text, message) in [
+            ("rows: 6\nplayer: Red A", "Missing \"columns\" line."),
+            ("columns: 7\nplayer: Red A", "Missing \"rows\" line."),
+            ("rows: six", "Line 1: invalid row count \"six\"."),
+            ("rows 6", "Line 1: expected \"key: value\"."),
+            ("size: 6", "Line 1: unknown key \"size\"."),
+            (
+                "rows: 6\ncolumns: 7\nplayer: Red A\nstart: 2",
+                "The starting player 2 is not one of the 1 players.",
+            ),
+        ] {
+            assert_eq!(parse_error(text.parse()), message);
+        }
+        assert_eq!(
+            parse_error(format!("{header}result: resign 3").parse()),
+            "The losing player 3 is not one of the 2 players."
+        );
+        assert_eq!(
+            parse_error(format!("{header}moves: 48").parse()),
+            "Move 2 (\"8\") is not a column number between 1 and 7."
+        );
+        for line in [
+            "player: Mauve A",
+            "result: won",
+            "result: resign 0",
+            "team: Red",
+            "team: Mauve Dragons,D,#ff6600",
+            "team: Red Dragons,D,#ff66",
+            "time: hourglass:60",
+            "clock: board",
+            "result: time 0",
+            "clocks: 60 soon",
+            "clocks: -1",
+        ] {
+            let error = parse_error(format!("{header}{line}").parse());
+            assert!(error.starts_with("Line 5: "), "{line}: {error}");
+        }
+    }
+
+    #[test]
+    fn time_controls_round_trip() {
+        let text = TEXT
+            .replace("start: 2", "time: fischer:300+5\nclock: team\nstart: 2")
+            .replace("result: resign 1", "result: time 2");
+        let record: GameRecord = text.parse().unwrap();
+        assert_eq!(
+            record.time_control,
+            Some(TimeControl::Fischer {
+                initial: std::time::Duration::from_secs(300),
+                increment: std::time::Duration::from_secs(5),
+            })
+        );
+        assert_eq!(record.clock_scope, ClockScope::Team);
+        assert_eq!(record.result, Some(EndReason::Timeout(1)));
+        assert_eq!(record.to_string(), text);
+        let text = text.replace("start: 2", "clocks: 287.5 0.25\nstart: 2");
+        let record: GameRecord = text.parse().unwrap();
+        assert_eq!(
+            record.clocks,
+            [
+                std::time::Duration::from_millis(287_500),
+                std::time::Duration::from_millis(250)
+            ]
+        );
+        assert_eq!(record.to_string(), text);
+        let untimed = GameRecord {
+            time_control: None,
+            ..record
+        };
+        assert!(!untimed.to_string().contains("clock:"));
+    }
+
+    #[test]
+    fn records_rebuild_their_position() {
+        let record: GameRecord = TEXT.parse().unwrap();
+        let board = record.to_board().unwrap();
+        assert_eq!(board.get_cell(5, 3), Some(Color::BLACK));
+        assert_eq!(board.get_cell(5, 2), Some(Color::RED));
+        assert_eq!(board.get_team(Color::RED).name, "Dragons");
+        let game = Game::from_record(&record, players(2)).unwrap();
+        assert_eq!(&board, game.get_board());
+        let record: GameRecord = "rows: 2\ncolumns: 3\nplayer: Red A\nplayer: Black B\nmoves: 111"
+            .parse()
+            .unwrap();
+        match record.to_board() {
+            Err(Connect4Error::Parse(error)) => assert_eq!(
+                error,
+                "Move 3 does not fit on the board: The column with index 0 is full."
+            ),
+            result => panic!("expected a parse error, got {result:?}"),
+        }
+        let mut empty = record.clone();
+        empty.num_columns = 0;
+        let mut no_players = record.clone();
+        no_players.player_colors.clear();
+        no_players.player_names.clear();
+        no_players.starting_player_index = 0;
+        let mut unknown_start = record.clone();
+        unknown_start.starting_player_index = 2;
+        for (record, message) in [
+            (
+                empty,
+                "The board must have at least one row and one column (got 2x0).",
+            ),
+            (
+                no_players,
+                "The starting player 1 is not one of the 0 players.",
+            ),
+            (
+                unknown_start,
+                "The starting player 3 is not one of the 2 players.",
+            ),
+        ] {
+            match record.to_board() {
+                Err(Connect4Error::Parse(error)) => assert_eq!(error, message),
+                result => panic!("expected a parse error, got {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn recorded_clocks_are_restored() {
+        let text = "rows: 6\ncolumns: 7\nplayer: Red A\nplayer: Black B\ntime: sudden:60\n\
+                    clocks: 41.5 30\nmoves: 44";
+        let record: GameRecord = text.parse().unwrap();
+        let game = Game::from_record(&record, players(2)).unwrap();
+        let clock = game.get_clock().unwrap();
+        assert_eq!(clock.time_left(0), std::time::Duration::from_millis(41_500));
+        assert_eq!(clock.time_left(1), std::time::Duration::from_secs(30));
+        assert_eq!(game.to_record().clocks, record.clocks);
+        let record: GameRecord = text.replace("41.5 30", "41.5").parse().unwrap();
+        match Game::from_record(&record, players(2)) {
+            Err(Connect4Error::Parse(error)) => assert_eq!(
+                error,
+                "The record has 1 clock times but the game has 2 clocks."
+            ),
+            result => panic!("expected a parse error, got {:?}", result.err()),
+        }
+    }
+
+    #[test]
+    fn games_round_trip_through_records() {
+        let record: GameRecord = TEXT.parse().unwrap();
+        let game = Game::from_record(&record, players(2)).unwrap();
+        let outcome = game.get_outcome().unwrap();
+        assert_eq!(outcome.reason, EndReason::Resignation(0));
+        assert_eq!(outcome.winning_color, Some(Color::BLACK));
+        assert_eq!(game.get_board().get_team(Color::RED).glyph, 'D');
+        let mut restored = game.to_record();
+        restored.player_names = record.player_names.clone();
+        assert_eq!(restored, record);
+    }
+
+    #[test]
+    fn impossible_games_are_rejected() {
+        let header = "rows: 6\ncolumns: 7\nplayer: Red A\nplayer: Black B\n";
+        for (lines, message) in [
+            (
+                "moves: 1111111",
+                "Move 7 is invalid: The column with index 0 is full.",
+            ),
+            (
+                "moves: 12121212",
+                "Move 8 is played after the game was won.",
+            ),
+            (
+                "result: connect\nmoves: 12",
+                "The record ends in a win but nobody won.",
+            ),
+            (
+                "result: tie\nmoves: 12",
+                "The record ends in a tie but the board is not a tie.",
+            ),
+        ] {
+            let record: GameRecord = format!("{header}{lines}").parse().unwrap();
+            match Game::from_record(&record, players(2)) {
+                Err(Connect4Error::Parse(error)) => assert_eq!(error, message),
+                result => panic!("{lines}: expected a parse error, got {:?}", result.err()),
+            }
+        }
+        let record: GameRecord = header.parse().unwrap();
+        assert!(matches!(
+            Game::from_record(&record, players(3)),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn files_round_trip() {
+        let path = std::env::temp_dir().join(format!("connect_4_record_{}.c4", std::process::id()));
+        let record: GameRecord = TEXT.parse().unwrap();
+        let game = Game::from_record(&record, players(2)).unwrap();
+        game.save(&path).unwrap();
+        let loaded = Game::load(&path, players(2));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap().to_record(), game.to_record());
+        assert!(matches!(
+            Game::load(&path, players(2)),
+            Err(Connect4Error::Io(_))
+        ));
+        std::fs::write(&path, "rows: six").unwrap();
+        let error = parse_error(GameRecord::load(&path));
+        std::fs::remove_file(&path).unwrap();
+        assert!(error.starts_with("Invalid game file "), "{error}");
+        assert!(
+            error.ends_with(": Line 1: invalid row count \"six\"."),
+            "{error}"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trips() {
+        let record: GameRecord = TEXT.parse().unwrap();
+        assert_eq!(
+            GameRecord::from_json(&record.to_json().unwrap()).unwrap(),
+            record
+        );
+        for invalid in ["{}", "not json"] {
+            assert!(matches!(
+                GameRecord::from_json(invalid),
+                Err(Connect4Error::Parse(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! A Connect 4 engine with configurable boards, teams of players and computer opponents.
+//!
+//! The crate is split into a few modules:
+//!
+//! * [`board`] - the `Board` pieces are dropped into, the piece `Color`s and the `Team`s
+//!   presenting them, a `BitBoard` for fast searches and compact move and grid notations.
+//! * [`game`] - the `Game` loop which seats players and plays until somebody wins, along with
+//!   `GameBuilder`, `GameOutcome` and `GameRecord` for saving and loading games, and the
+//!   `GameObserver` trait for following games as they are played.
+//! * [`players`] - the `Player` trait and the built-in terminal and computer players.
+//! * [`rendering`] - printing boards to the terminal and the `TerminalObserver` which shows
+//!   games there.
+//! * [`simulation`] - running many headless games between computer players and collecting
+//!   statistics about them.
+//! * [`solver`] - a perfect solver for the standard 7x6 board.
+//!
+//! Every fallible operation returns a [`Connect4Error`] describing what went wrong.
+//!
+//! The most commonly used items are also re-exported at the crate root.
+//!
+//! # Examples
+//!
+//! A custom bot only has to pick a column:
+//!
+//! ```
+//! use connect_4::{Color, Connect4Error, Game, Player, TurnContext};
+//!
+//! /// Plays the leftmost available column.
+//! struct LeftmostPlayer;
+//!
+//! impl Player for LeftmostPlayer {
+//!     fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+//!         (0..ctx.board.num_columns)
+//!             .find(|col_index| ctx.board.available_column(*col_index))
+//!             .ok_or(Connect4Error::BoardFull)
+//!     }
+//!
+//!     fn get_name(&self) -> String {
+//!         "Lefty".into()
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Connect4Error> {
+//! let mut game = Game::builder()
+//!     .player(Box::new(LeftmostPlayer), Color::RED)
+//!     .player(Box::new(LeftmostPlayer), Color::BLACK)
+//!     .build()?;
+//! let outcome = game.start()?;
+//! assert_eq!(outcome.winning_color, Some(Color::RED));
+//! # Ok(())
+//! # }
+//! ```
+
+mod error;
+pub use error::Connect4Error;
+
+pub mod board;
+pub use board::{format_moves, parse_moves, BitBoard, Board, Color, Position, Team};
+
+pub mod game;
+pub use game::{
+    ClockOwner, ClockScope, EndReason, Game, GameBuilder, GameClock, GameEvent, GameObserver,
+    GameOutcome, GameRecord, TimeControl,
+};
+
+pub mod players;
+pub use players::*;
+
+pub mod rendering;
+pub use rendering::{clear_screen, ColorMode, PieceStyle, TerminalObserver};
+
+pub mod simulation;
+
+pub mod solver;
+pub use solver::{Outcome, Score, Solver};
@@ -1,23 +1,13 @@
-mod core;
-#[allow(unused_imports)]
-use core::{AIPlayer, Board, Color, Game, Player, TerminalPlayer};
+mod cli;
 
-fn run_game() -> Result<(), String> {
-    let players: Vec<Box<dyn Player>> = vec![
-        Box::new(TerminalPlayer::new("Player 1".into())),
-        Box::new(AIPlayer::new("Bot 1".into())),
-        Box::new(AIPlayer::new("Bot 2".into())),
-        Box::new(AIPlayer::new("Bot 3".into())),
-    ];
-    let player_colors = vec![Color::Red, Color::Black, Color::Red, Color::Black];
+use std::process::ExitCode;
 
-    let mut game = Game::new(None, players, player_colors)?;
-    game.start()?;
-    Ok(())
-}
-
-fn main() {
-    if let Err(msg) = run_game() {
-        eprintln!("[ERROR] {msg}");
+fn main() -> ExitCode {
+    match cli::run(std::env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("[ERROR] {err}");
+            ExitCode::FAILURE
+        }
     }
 }
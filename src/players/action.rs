@@ -0,0 +1,24 @@
+/// An action a player can choose to take on their turn or in response to a draw offer.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Action {
+    /// Drops a game piece in the column with the given index.
+    Drop(usize),
+    /// Takes back every move made since the player's previous turn.
+    Undo,
+    /// Replays the moves taken back by the most recent `Undo`.
+    Redo,
+    /// Gives up the game on behalf of the player's team.
+    Resign,
+    /// Offers a draw to the other teams, whose players are asked to accept or decline it (see
+    /// `TurnContext::draw_offer`). The game ends in a draw if all of them accept; otherwise the
+    /// player is asked again and cannot offer another draw until their next turn.
+    OfferDraw,
+    /// Accepts the draw offered in `TurnContext::draw_offer`.
+    AcceptDraw,
+    /// Declines the draw offered in `TurnContext::draw_offer`. Any action other than
+    /// `AcceptDraw` taken in response to an offer declines it as well.
+    DeclineDraw,
+    /// Saves the game to its save path (see `Game::set_save_path`) and asks the player again.
+    /// Rejected with a `GameEvent::ActionRejected` if the game has no save path.
+    Save,
+}
@@ -0,0 +1,258 @@
+use crate::{Connect4Error, MinimaxPlayer, PerfectPlayer, Player, TurnContext};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::Duration;
+
+/// The time the `PerfectPlayer` of a `Difficulty::Perfect` bot may take for a move.
+const PERFECT_TIME_LIMIT: Duration = Duration::from_secs(5);
+
+/// The strength of an `AIPlayer`.
+///
+/// Every level below `Hard` samples its moves from a softmax over the search scores of every
+/// column instead of always playing the best one, so weaker bots make believable mistakes
+/// rather than purely random moves.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Difficulty {
+    /// Plays a random available column.
+    Random,
+    /// Looks one move ahead and often plays suboptimal moves.
+    Easy,
+    /// Looks four moves ahead and occasionally plays suboptimal moves.
+    Medium,
+    /// Looks seven moves ahead and always plays the best move it finds.
+    Hard,
+    /// Plays with a `PerfectPlayer` in the games it supports (see `PerfectPlayer::supports`),
+    /// taking up to 5 seconds for a move, and as `Hard` with a deeper search in any other game.
+    Perfect,
+}
+
+impl Difficulty {
+    /// Every difficulty level from weakest to strongest.
+    pub const ALL: [Difficulty; 5] = [
+        Difficulty::Random,
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Perfect,
+    ];
+
+    /// Returns the search depth and the softmax temperature used at this level, or `None` for
+    /// `Random`.
+    ///
+    /// The temperature is in units of the clamped search scores (see `SCORE_CLAMP`); a
+    /// temperature of `0.0` always plays the best move.
+    fn search_settings(&self) -> Option<(usize, f64)> {
+        match self {
+            Difficulty::Random => None,
+            Difficulty::Easy => Some((1, 24.0)),
+            Difficulty::Medium => Some((4, 6.0)),
+            Difficulty::Hard => Some((7, 0.0)),
+            Difficulty::Perfect => Some((9, 0.0)),
+        }
+    }
+}
+
+impl std::fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::str::FromStr for Difficulty {
+    type Err = Connect4Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                Connect4Error::Parse(format!(
+                    "Unknown difficulty \"{s}\" (expected one of {}).",
+                    Difficulty::ALL
+                        .map(|difficulty| difficulty.to_string())
+                        .join(", ")
+                ))
+            })
+    }
+}
+
+/// The magnitude search scores are clamped to before the softmax, which keeps forced wins and
+/// losses from drowning out the heuristic scores.
+const SCORE_CLAMP: f64 = 1000.0;
+
+pub struct AIPlayer {
+    name: String,
+    difficulty: Difficulty,
+    /// The solver backed player used at the `Perfect` difficulty.
+    perfect_player: Option<PerfectPlayer>,
+    rng: StdRng,
+}
+
+impl AIPlayer {
+    /// Constructs an `AIPlayer` which plays random moves.
+    pub fn new(name: String) -> Self {
+        Self::with_difficulty(name, Difficulty::Random)
+    }
+
+    /// Constructs an `AIPlayer` which plays at the given `Difficulty` with a randomly seeded
+    /// random number generator.
+    pub fn with_difficulty(name: String, difficulty: Difficulty) -> Self {
+        Self::with_seed(name, difficulty, rand::thread_rng().gen())
+    }
+
+    /// Constructs an `AIPlayer` which plays at the given `Difficulty` and whose random number
+    /// generator is seeded with `seed`, which makes its moves reproducible.
+    pub fn with_seed(name: String, difficulty: Difficulty, seed: u64) -> Self {
+        let perfect_player = (difficulty == Difficulty::Perfect)
+            .then(|| PerfectPlayer::with_time_limit(name.clone(), PERFECT_TIME_LIMIT));
+        AIPlayer {
+            name,
+            difficulty,
+            perfect_player,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns the `Difficulty` the player plays at.
+    pub fn get_difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    /// Returns the index of a random available column.
+    fn random_column(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        let board = ctx.board;
+        if board.is_full() {
+            return Err(Connect4Error::BoardFull);
+        }
+        loop {
+            let col_index = self.rng.gen_range(0..board.num_columns);
+            if board.available_column(col_index) {
+                break Ok(col_index);
+            }
+        }
+    }
+
+    /// Returns the index of a column sampled from a softmax over the scores of a `depth` ply
+    /// search with the given `temperature`.
+    fn sampled_column(
+        &mut self,
+        ctx: &TurnContext,
+        depth: usize,
+        temperature: f64,
+    ) -> Result<usize, Connect4Error> {
+        let scores = MinimaxPlayer::new(self.name.clone(), depth)
+            .score_columns(ctx)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(col_index, score)| {
+                score.map(|score| (col_index, (score as f64).clamp(-SCORE_CLAMP, SCORE_CLAMP)))
+            })
+            .collect::<Vec<(usize, f64)>>();
+        if scores.is_empty() {
+            return Err(Connect4Error::BoardFull);
+        }
+        let best = scores
+            .iter()
+            .map(|(_, score)| *score)
+            .fold(f64::NEG_INFINITY, f64::max);
+        if temperature <= 0.0 {
+            // prefer the center-most of equally good columns
+            let center = ctx.board.num_columns as f64 / 2.0 - 0.5;
+            return scores
+                .iter()
+                .filter(|(_, score)| *score == best)
+                .min_by(|(a, _), (b, _)| {
+                    (*a as f64 - center)
+                        .abs()
+                        .total_cmp(&(*b as f64 - center).abs())
+                })
+                .map(|(col_index, _)| *col_index)
+                .ok_or(Connect4Error::BoardFull);
+        }
+        let weights = scores
+            .iter()
+            .map(|(_, score)| ((score - best) / temperature).exp())
+            .collect::<Vec<f64>>();
+        let mut target = self.rng.gen_range(0.0..weights.iter().sum::<f64>());
+        for ((col_index, _), weight) in scores.iter().zip(weights.iter()) {
+            if target < *weight {
+                return Ok(*col_index);
+            }
+            target -= weight;
+        }
+        scores
+            .last()
+            .map(|(col_index, _)| *col_index)
+            .ok_or(Connect4Error::BoardFull)
+    }
+}
+
+impl Player for AIPlayer {
+    fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        if let Some(perfect_player) = &mut self.perfect_player {
+            if PerfectPlayer::supports(ctx) {
+                return perfect_player.get_column_index(ctx);
+            }
+        }
+        match self.difficulty.search_settings() {
+            None => self.random_column(ctx),
+            Some((depth, temperature)) => self.sampled_column(ctx, depth, temperature),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Board, Color};
+
+    const COLORS: [Color; 2] = [Color::RED, Color::BLACK];
+
+    /// Returns the context of Red's first turn on `board`.
+    fn context(board: &Board) -> TurnContext<'_> {
+        TurnContext {
+            board,
+            color: Color::RED,
+            amount_to_win: 4,
+            player_colors: &COLORS,
+            player_names: &[],
+            player_index: 0,
+            time_left: None,
+            clock: None,
+            draw_offer: None,
+            draw_declined_by: None,
+        }
+    }
+
+    #[test]
+    fn perfect_bots_search_in_unsupported_games() {
+        let mut player = AIPlayer::with_difficulty("Bot".into(), Difficulty::Perfect);
+        let board = Board::new(6, 7, 3, 7);
+        assert_eq!(player.get_column_index(&context(&board)).unwrap(), 3);
+        let small = Board::new(4, 5, 3, 7);
+        let col_index = player.get_column_index(&context(&small)).unwrap();
+        assert!(small.available_column(col_index));
+    }
+
+    #[test]
+    fn perfect_player_errors_are_passed_on() {
+        let mut player = AIPlayer::with_difficulty("Bot".into(), Difficulty::Perfect);
+        let mut board = Board::new(6, 7, 3, 7);
+        // fills the board without four in a row
+        for col_index in 0..7 {
+            for turn in 0..6 {
+                let color = COLORS[(turn + col_index / 2) % 2];
+                board.drop_piece(color, col_index).unwrap();
+            }
+        }
+        assert!(player.get_column_index(&context(&board)).is_err());
+    }
+}
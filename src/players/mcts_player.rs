@@ -0,0 +1,315 @@
+use crate::{Color, Connect4Error, Player, Position, TurnContext};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+/// The exploration constant of the UCT formula.
+const EXPLORATION: f64 = std::f64::consts::SQRT_2;
+
+/// How much thinking a `MctsPlayer` does before picking a move.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Budget {
+    /// Runs a fixed number of search iterations.
+    Iterations(usize),
+    /// Runs search iterations until the given amount of time has passed.
+    Time(Duration),
+}
+
+/// A player which picks its moves with a Monte Carlo tree search.
+///
+/// Nodes are selected with the UCT formula and positions are evaluated by playing random moves
+/// until the game ends, so no board size or win length specific heuristic is needed. Every
+/// player is assumed to play for their own team, which makes the search work for any number of
+/// teams.
+///
+/// In timed games the search also stops once the time budgeted for the move runs out (see
+/// `TurnContext::time_budget`), whatever its `Budget`.
+pub struct MctsPlayer {
+    name: String,
+    budget: Budget,
+    rng: StdRng,
+}
+
+impl MctsPlayer {
+    /// Constructs a `MctsPlayer` with a randomly seeded random number generator.
+    pub fn new(name: String, budget: Budget) -> Self {
+        Self::with_seed(name, budget, rand::thread_rng().gen())
+    }
+
+    /// Constructs a `MctsPlayer` whose random number generator is seeded with `seed`, which
+    /// makes its moves reproducible when given the same iteration budget.
+    pub fn with_seed(name: String, budget: Budget, seed: u64) -> Self {
+        MctsPlayer {
+            name,
+            budget,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+/// A node of the search tree.
+struct Node {
+    /// The column index of the move leading to this node.
+    col_index: usize,
+    /// The `Color` of the player who made the move leading to this node.
+    color: Color,
+    /// The index of the parent node in the tree.
+    parent: Option<usize>,
+    /// The indices of the expanded child nodes in the tree.
+    children: Vec<usize>,
+    /// The column indices of the moves which have not been expanded yet.
+    untried: Vec<usize>,
+    /// The result of the game if it ended with the move leading to this node, where
+    /// `Some(None)` is a tie.
+    result: Option<Option<Color>>,
+    /// The number of iterations which passed through this node.
+    visits: f64,
+    /// The total reward of those iterations for `color`.
+    reward: f64,
+}
+
+/// The state of a single search from the position given to the player.
+struct Search<'a, 'r, P: Position> {
+    position: P,
+    ctx: TurnContext<'a>,
+    rng: &'r mut StdRng,
+    nodes: Vec<Node>,
+}
+
+impl<'a, 'r, P: Position> Search<'a, 'r, P> {
+    fn new(position: P, ctx: TurnContext<'a>, rng: &'r mut StdRng) -> Self {
+        let root = Node {
+            col_index: 0,
+            color: ctx.color_after(ctx.player_colors.len() - 1),
+            parent: None,
+            children: Vec::new(),
+            untried: available_columns(&position),
+            result: None,
+            visits: 0.0,
+            reward: 0.0,
+        };
+        Search {
+            position,
+            ctx,
+            rng,
+            nodes: vec![root],
+        }
+    }
+
+    /// Plays `col_index` for the player `ply` turns after the active player and returns the
+    /// result of the game if it ended with the move.
+    fn play(&mut self, col_index: usize, ply: usize) -> Option<Option<Color>> {
+        let color = self.ctx.color_after(ply);
+        let row_index = self.position.drop_piece(color, col_index).ok()?;
+        if self
+            .position
+            .is_winning_cell(row_index, col_index, self.ctx.amount_to_win)
+        {
+            Some(Some(color))
+        } else if self.position.is_full() {
+            Some(None)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the child of `node_index` with the highest UCT value.
+    fn select_child(&self, node_index: usize) -> usize {
+        let node = &self.nodes[node_index];
+        let log_visits = node.visits.ln();
+        let uct = |child: &Node| {
+            child.reward / child.visits + EXPLORATION * (log_visits / child.visits).sqrt()
+        };
+        *node
+            .children
+            .iter()
+            .max_by(|a, b| uct(&self.nodes[**a]).total_cmp(&uct(&self.nodes[**b])))
+            .expect("a fully expanded node has children")
+    }
+
+    /// Runs a single selection, expansion, simulation and backpropagation step.
+    fn iterate(&mut self) {
+        let mut node_index = 0;
+        let mut played = Vec::new();
+        // selection
+        while self.nodes[node_index].result.is_none()
+            && self.nodes[node_index].untried.is_empty()
+            && !self.nodes[node_index].children.is_empty()
+        {
+            node_index = self.select_child(node_index);
+            let col_index = self.nodes[node_index].col_index;
+            self.play(col_index, played.len());
+            played.push(col_index);
+        }
+        let mut result = self.nodes[node_index].result;
+        // expansion
+        if result.is_none() && !self.nodes[node_index].untried.is_empty() {
+            let untried = &mut self.nodes[node_index].untried;
+            let col_index = untried.swap_remove(self.rng.gen_range(0..untried.len()));
+            let color = self.ctx.color_after(played.len());
+            result = self.play(col_index, played.len());
+            played.push(col_index);
+            self.nodes.push(Node {
+                col_index,
+                color,
+                parent: Some(node_index),
+                children: Vec::new(),
+                untried: if result.is_none() {
+                    available_columns(&self.position)
+                } else {
+                    Vec::new()
+                },
+                result,
+                visits: 0.0,
+                reward: 0.0,
+            });
+            let child_index = self.nodes.len() - 1;
+            self.nodes[node_index].children.push(child_index);
+            node_index = child_index;
+        }
+        // simulation
+        let mut rollout = Vec::new();
+        while result.is_none() {
+            let columns = available_columns(&self.position);
+            let Some(col_index) = columns.choose(self.rng).copied() else {
+                break;
+            };
+            result = self.play(col_index, played.len() + rollout.len());
+            rollout.push(col_index);
+        }
+        let winner = result.flatten();
+        // backpropagation
+        let mut current = Some(node_index);
+        while let Some(index) = current {
+            let node = &mut self.nodes[index];
+            node.visits += 1.0;
+            node.reward += match winner {
+                Some(color) if color == node.color => 1.0,
+                Some(_) => 0.0,
+                None => 0.5,
+            };
+            current = node.parent;
+        }
+        for col_index in played.into_iter().chain(rollout).rev() {
+            let _ = self.position.undo_piece(col_index);
+        }
+    }
+
+    /// Runs iterations within `budget`, stopping early at `deadline` if given, and returns the
+    /// column index of the most visited move.
+    fn best_column(
+        &mut self,
+        budget: Budget,
+        deadline: Option<Instant>,
+    ) -> Result<usize, Connect4Error> {
+        if self.nodes[0].untried.is_empty() {
+            return Err(Connect4Error::BoardFull);
+        }
+        let start = Instant::now();
+        let (iterations, deadline) = match budget {
+            Budget::Iterations(iterations) => (iterations.max(1), deadline),
+            Budget::Time(duration) => {
+                let end = start + duration;
+                (
+                    usize::MAX,
+                    Some(deadline.map_or(end, |deadline| deadline.min(end))),
+                )
+            }
+        };
+        for _ in 0..iterations {
+            self.iterate();
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                break;
+            }
+        }
+        self.nodes[0]
+            .children
+            .iter()
+            .map(|index| &self.nodes[*index])
+            .max_by(|a, b| a.visits.total_cmp(&b.visits))
+            .map(|node| node.col_index)
+            .ok_or(Connect4Error::BoardFull)
+    }
+}
+
+/// Returns the column indices of every available column of `position`.
+fn available_columns<P: Position>(position: &P) -> Vec<usize> {
+    (0..position.num_columns())
+        .filter(|col_index| position.available_column(*col_index))
+        .collect()
+}
+
+impl Player for MctsPlayer {
+    fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        let deadline = ctx
+            .time_budget()
+            .map(|time_budget| Instant::now() + time_budget);
+        match ctx.board.bitboard() {
+            Some(bits) => {
+                Search::new(bits.clone(), *ctx, &mut self.rng).best_column(self.budget, deadline)
+            }
+            None => Search::new(ctx.board.clone(), *ctx, &mut self.rng)
+                .best_column(self.budget, deadline),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Board;
+
+    const COLORS: [Color; 2] = [Color::RED, Color::BLACK];
+
+    /// Returns the column a seeded player with `budget` picks after the 1-based column digits of
+    /// `moves` are played on a 6x7 board, with the players alternating from red.
+    fn pick(moves: &str, budget: Budget) -> usize {
+        let mut board = Board::new(6, 7, 3, 7);
+        for (turn, digit) in moves.chars().enumerate() {
+            let col_index = digit.to_digit(10).unwrap() as usize - 1;
+            board.drop_piece(COLORS[turn % 2], col_index).unwrap();
+        }
+        let ctx = TurnContext {
+            board: &board,
+            color: COLORS[moves.len() % 2],
+            amount_to_win: 4,
+            player_colors: &COLORS,
+            player_names: &[],
+            player_index: moves.len() % 2,
+            time_left: None,
+            clock: None,
+            draw_offer: None,
+            draw_declined_by: None,
+        };
+        MctsPlayer::with_seed("Bot".into(), budget, 7)
+            .get_column_index(&ctx)
+            .unwrap()
+    }
+
+    #[test]
+    fn takes_a_win_in_one() {
+        // red can complete the bottom row or block black's column
+        assert_eq!(pick("152535", Budget::Iterations(2000)), 3);
+    }
+
+    #[test]
+    fn blocks_a_loss_in_one() {
+        assert_eq!(pick("15253", Budget::Iterations(2000)), 3);
+    }
+
+    #[test]
+    fn stops_searching_when_the_time_budget_runs_out() {
+        let start = Instant::now();
+        pick("", Budget::Time(Duration::from_millis(100)));
+        assert!(start.elapsed() < Duration::from_millis(200));
+    }
+}
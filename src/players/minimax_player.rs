@@ -0,0 +1,447 @@
+use crate::{Color, Connect4Error, Player, Position, TurnContext};
+use std::time::{Duration, Instant};
+
+/// The score of a won position before it is reduced by the number of plies needed to reach it.
+const WIN_SCORE: i32 = 1_000_000_000;
+
+/// A bound larger than any score the search can return.
+const INFINITY: i32 = 2 * WIN_SCORE;
+
+/// The number of positions searched between checks of the deadline.
+const DEADLINE_CHECK_INTERVAL: u64 = 64;
+
+/// A player which picks its moves with a depth limited negamax search with alpha-beta pruning.
+///
+/// Positions at the depth limit are scored with a heuristic over every window of
+/// `amount_to_win` cells that could still be completed by a single team.
+///
+/// When more than two players take part, every player on another team is assumed to play
+/// against the active player's team.
+///
+/// Whenever the player has a time budget or the game is timed, the search deepens iteratively
+/// until time runs out and plays the best move of the deepest completed iteration. In timed
+/// games the player spreads its time over the rest of the game (see `TurnContext::time_budget`).
+pub struct MinimaxPlayer {
+    name: String,
+    depth: usize,
+    time_budget: Option<Duration>,
+}
+
+impl MinimaxPlayer {
+    /// Constructs a `MinimaxPlayer` which searches `depth` plies ahead.
+    pub fn new(name: String, depth: usize) -> Self {
+        MinimaxPlayer {
+            name,
+            depth: depth.max(1),
+            time_budget: None,
+        }
+    }
+
+    /// Constructs a `MinimaxPlayer` which searches as deep as it can within `time_budget` for
+    /// every move.
+    pub fn with_time_budget(name: String, time_budget: Duration) -> Self {
+        MinimaxPlayer {
+            name,
+            depth: usize::MAX,
+            time_budget: Some(time_budget),
+        }
+    }
+
+    /// Returns the score of dropping a piece in each column for the turn described by `ctx`, or
+    /// `None` for unavailable columns.
+    ///
+    /// Scores are from the perspective of the active player's team; higher is better. Forced wins
+    /// and losses score far beyond any heuristic score. Unlike `get_column_index`, every column
+    /// is searched with an open window. Under a time limit the search deepens one ply at a time
+    /// as in `get_column_index` and the scores of the deepest iteration that finished in time
+    /// are returned.
+    pub fn score_columns(&self, ctx: &TurnContext) -> Vec<Option<i32>> {
+        let deadline = self
+            .get_time_limit(ctx)
+            .map(|time_limit| Instant::now() + time_limit);
+        match ctx.board.bitboard() {
+            Some(bits) => Search::new(bits.clone(), *ctx, deadline).deepest_scores(self.depth),
+            None => Search::new(ctx.board.clone(), *ctx, deadline).deepest_scores(self.depth),
+        }
+    }
+
+    /// Returns the time the search may take for the turn described by `ctx`, if limited.
+    fn get_time_limit(&self, ctx: &TurnContext) -> Option<Duration> {
+        let time_left = ctx.time_budget();
+        match (self.time_budget, time_left) {
+            (Some(budget), Some(time_left)) => Some(budget.min(time_left)),
+            (budget, time_left) => budget.or(time_left),
+        }
+    }
+}
+
+/// The state of a single search from the position given to the player.
+struct Search<'a, P: Position> {
+    /// The position being searched, which moves are made on and unmade from.
+    position: P,
+    /// The context of the turn the search was started for.
+    ctx: TurnContext<'a>,
+    /// The cells of every line of `amount_to_win` cells on the board.
+    windows: Vec<Vec<(usize, usize)>>,
+    /// The column indices in the order they are searched in, center first.
+    column_order: Vec<usize>,
+    /// The number of empty cells in the position the search was started from.
+    empty_cells: usize,
+    /// The point in time the search has to stop at, if limited.
+    deadline: Option<Instant>,
+    /// The number of positions searched so far.
+    node_count: u64,
+    /// Whether the deadline passed during the current iteration.
+    aborted: bool,
+}
+
+impl<'a, P: Position> Search<'a, P> {
+    fn new(position: P, ctx: TurnContext<'a>, deadline: Option<Instant>) -> Self {
+        let (num_rows, num_columns) = (position.num_rows(), position.num_columns());
+        let length = ctx.amount_to_win as isize;
+        let mut windows = Vec::new();
+        for (row_step, col_step) in [(0, 1), (1, 0), (1, 1), (1, -1)] {
+            for row_index in 0..num_rows as isize {
+                for col_index in 0..num_columns as isize {
+                    let last_row = row_index + row_step * (length - 1);
+                    let last_col = col_index + col_step * (length - 1);
+                    if length == 0
+                        || last_row >= num_rows as isize
+                        || last_col < 0
+                        || last_col >= num_columns as isize
+                    {
+                        continue;
+                    }
+                    windows.push(
+                        (0..length)
+                            .map(|i| {
+                                (
+                                    (row_index + row_step * i) as usize,
+                                    (col_index + col_step * i) as usize,
+                                )
+                            })
+                            .collect(),
+                    );
+                }
+            }
+        }
+        let mut column_order = (0..num_columns).collect::<Vec<usize>>();
+        column_order.sort_by_key(|col_index| (2 * *col_index).abs_diff(num_columns - 1));
+        let empty_cells = (0..num_rows)
+            .flat_map(|row_index| (0..num_columns).map(move |col_index| (row_index, col_index)))
+            .filter(|(row_index, col_index)| position.get_cell(*row_index, *col_index).is_none())
+            .count();
+        Search {
+            position,
+            ctx,
+            windows,
+            column_order,
+            empty_cells,
+            deadline,
+            node_count: 0,
+            aborted: false,
+        }
+    }
+
+    /// Returns `true` if `color` is on the team of the player the search was started for.
+    fn is_own_team(&self, color: Color) -> bool {
+        color == self.ctx.color
+    }
+
+    /// Returns the heuristic score of the position from the perspective of `color`'s side.
+    fn evaluate(&self, color: Color) -> i32 {
+        let mut score = 0;
+        for window in self.windows.iter() {
+            let mut owner = None;
+            let mut count = 0;
+            for (row_index, col_index) in window.iter() {
+                match self.position.get_cell(*row_index, *col_index) {
+                    Some(piece) if owner.is_none() || owner == Some(piece) => {
+                        owner = Some(piece);
+                        count += 1;
+                    }
+                    Some(_) => {
+                        owner = None;
+                        break;
+                    }
+                    None => {}
+                }
+            }
+            if let Some(owner) = owner {
+                let weight = 1 << (2 * (count - 1)).min(12);
+                if self.is_own_team(owner) {
+                    score += weight;
+                } else {
+                    score -= weight;
+                }
+            }
+        }
+        if self.is_own_team(color) {
+            score
+        } else {
+            -score
+        }
+    }
+
+    /// Plays `col_index` for the player `ply` turns after the active player and returns the
+    /// score of the resulting position from that player's side, searching `depth - 1` further
+    /// plies.
+    fn score_move(
+        &mut self,
+        col_index: usize,
+        ply: usize,
+        depth: usize,
+        alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        let color = self.ctx.color_after(ply);
+        let next_color = self.ctx.color_after(ply + 1);
+        let row_index = match self.position.drop_piece(color, col_index) {
+            Ok(row_index) => row_index,
+            Err(_) => return -INFINITY,
+        };
+        let score = if self
+            .position
+            .is_winning_cell(row_index, col_index, self.ctx.amount_to_win)
+        {
+            WIN_SCORE - ply as i32 - 1
+        } else if self.position.is_full() {
+            0
+        } else if depth <= 1 {
+            self.evaluate(color)
+        } else if self.is_own_team(color) == self.is_own_team(next_color) {
+            self.negamax(ply + 1, depth - 1, alpha, beta)
+        } else {
+            -self.negamax(ply + 1, depth - 1, -beta, -alpha)
+        };
+        let _ = self.position.undo_piece(col_index);
+        score
+    }
+
+    /// Returns the score of the position for the side of the player `ply` turns after the active
+    /// player, searching `depth` plies ahead within the window `alpha`..`beta`.
+    fn negamax(&mut self, ply: usize, depth: usize, mut alpha: i32, beta: i32) -> i32 {
+        self.node_count += 1;
+        if self.node_count.is_multiple_of(DEADLINE_CHECK_INTERVAL)
+            && self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            self.aborted = true;
+        }
+        if self.aborted {
+            return 0;
+        }
+        let mut best = -INFINITY;
+        for i in 0..self.column_order.len() {
+            let col_index = self.column_order[i];
+            if !self.position.available_column(col_index) {
+                continue;
+            }
+            let score = self.score_move(col_index, ply, depth, alpha, beta);
+            best = best.max(score);
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    }
+
+    /// Returns the index and score of the column with the best score when searching `depth`
+    /// plies ahead, trying the columns in `root_order`.
+    ///
+    /// Returns `None` if there are no available columns or the deadline passed.
+    fn search_root(&mut self, depth: usize, root_order: &[usize]) -> Option<(usize, i32)> {
+        let mut best: Option<(usize, i32)> = None;
+        for col_index in root_order.iter() {
+            if !self.position.available_column(*col_index) {
+                continue;
+            }
+            let alpha = best.map_or(-INFINITY, |(_, score)| score);
+            let score = self.score_move(*col_index, 0, depth, alpha, INFINITY);
+            if self.aborted {
+                return None;
+            }
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((*col_index, score));
+            }
+        }
+        best
+    }
+
+    /// Returns the exact score of dropping a piece in each column when searching `depth` plies
+    /// ahead, or `None` for unavailable columns.
+    ///
+    /// Returns `None` instead if the deadline passed.
+    fn score_columns(&mut self, depth: usize) -> Option<Vec<Option<i32>>> {
+        let scores = (0..self.position.num_columns())
+            .map(|col_index| {
+                self.position
+                    .available_column(col_index)
+                    .then(|| self.score_move(col_index, 0, depth, -INFINITY, INFINITY))
+            })
+            .collect();
+        (!self.aborted).then_some(scores)
+    }
+
+    /// Returns the scores of `score_columns` when searching up to `max_depth` plies ahead.
+    ///
+    /// Without a deadline the search goes straight to `max_depth`. With one, it deepens one ply
+    /// at a time and returns the scores of the deepest iteration that finished in time; a one ply
+    /// search never checks the deadline, so there always is one.
+    fn deepest_scores(&mut self, max_depth: usize) -> Vec<Option<i32>> {
+        let max_depth = max_depth.min(self.empty_cells).max(1);
+        let first_depth = if self.deadline.is_none() {
+            max_depth
+        } else {
+            1
+        };
+        let mut best = Vec::new();
+        for depth in first_depth..=max_depth {
+            match self.score_columns(depth) {
+                Some(scores) => best = scores,
+                None => break,
+            }
+        }
+        best
+    }
+
+    /// Returns the index of the column with the best score when searching up to `max_depth`
+    /// plies ahead.
+    ///
+    /// Without a deadline the search goes straight to `max_depth`. With one, it deepens one ply
+    /// at a time, trying the previous iteration's best column first, and returns the result of
+    /// the deepest iteration that finished in time.
+    fn best_column(&mut self, max_depth: usize) -> Result<usize, Connect4Error> {
+        let max_depth = max_depth.min(self.empty_cells).max(1);
+        let mut root_order = self.column_order.clone();
+        if self.deadline.is_none() {
+            return self
+                .search_root(max_depth, &root_order)
+                .map(|(col_index, _)| col_index)
+                .ok_or(Connect4Error::BoardFull);
+        }
+        let mut best = None;
+        for depth in 1..=max_depth {
+            let Some((col_index, score)) = self.search_root(depth, &root_order) else {
+                break;
+            };
+            best = Some(col_index);
+            if score.abs() >= WIN_SCORE - self.empty_cells as i32 {
+                // the outcome is already forced, searching deeper cannot change the move
+                break;
+            }
+            root_order.retain(|other| *other != col_index);
+            root_order.insert(0, col_index);
+        }
+        // fall back to the first available column if not even one ply could be searched
+        best.or_else(|| {
+            root_order
+                .into_iter()
+                .find(|col_index| self.position.available_column(*col_index))
+        })
+        .ok_or(Connect4Error::BoardFull)
+    }
+}
+
+impl Player for MinimaxPlayer {
+    fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        let deadline = self
+            .get_time_limit(ctx)
+            .map(|time_limit| Instant::now() + time_limit);
+        match ctx.board.bitboard() {
+            Some(bits) => Search::new(bits.clone(), *ctx, deadline).best_column(self.depth),
+            None => Search::new(ctx.board.clone(), *ctx, deadline).best_column(self.depth),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Board;
+
+    const COLORS: [Color; 2] = [Color::RED, Color::BLACK];
+
+    /// Returns the column the player picks after the 1-based column digits of `moves` are played
+    /// on a board of the given size, with the players alternating from red.
+    fn pick(player: &mut MinimaxPlayer, moves: &str, num_rows: usize, num_columns: usize) -> usize {
+        let mut board = Board::new(num_rows, num_columns, 3, 7);
+        for (turn, digit) in moves.chars().enumerate() {
+            let col_index = digit.to_digit(10).unwrap() as usize - 1;
+            board.drop_piece(COLORS[turn % 2], col_index).unwrap();
+        }
+        let ctx = TurnContext {
+            board: &board,
+            color: COLORS[moves.len() % 2],
+            amount_to_win: 4,
+            player_colors: &COLORS,
+            player_names: &[],
+            player_index: moves.len() % 2,
+            time_left: None,
+            clock: None,
+            draw_offer: None,
+            draw_declined_by: None,
+        };
+        player.get_column_index(&ctx).unwrap()
+    }
+
+    #[test]
+    fn takes_a_win_in_one() {
+        // red can complete the bottom row or block black's column
+        for (num_rows, num_columns) in [(6, 7), (12, 12)] {
+            let mut player = MinimaxPlayer::new("Red".into(), 4);
+            assert_eq!(pick(&mut player, "152535", num_rows, num_columns), 3);
+        }
+    }
+
+    #[test]
+    fn blocks_a_loss_in_one() {
+        for (num_rows, num_columns) in [(6, 7), (12, 12)] {
+            let mut player = MinimaxPlayer::new("Black".into(), 4);
+            assert_eq!(pick(&mut player, "15253", num_rows, num_columns), 3);
+        }
+    }
+
+    #[test]
+    fn stops_searching_when_the_time_budget_runs_out() {
+        let board = Board::new(6, 7, 3, 7);
+        let ctx = TurnContext {
+            board: &board,
+            color: Color::RED,
+            amount_to_win: 4,
+            player_colors: &COLORS,
+            player_names: &[],
+            player_index: 0,
+            time_left: Some(Duration::from_millis(200)),
+            clock: None,
+            draw_offer: None,
+            draw_declined_by: None,
+        };
+        let start = Instant::now();
+        assert!(MinimaxPlayer::new("Red".into(), 42)
+            .get_column_index(&ctx)
+            .is_ok());
+        assert!(start.elapsed() < Duration::from_millis(200));
+        let start = Instant::now();
+        let mut player = MinimaxPlayer::with_time_budget("Red".into(), Duration::from_millis(100));
+        assert!(player
+            .get_column_index(&TurnContext {
+                time_left: None,
+                clock: None,
+                ..ctx
+            })
+            .is_ok());
+        assert!(start.elapsed() < Duration::from_millis(200));
+        let start = Instant::now();
+        let scores = MinimaxPlayer::new("Red".into(), 42).score_columns(&ctx);
+        assert!(start.elapsed() < Duration::from_millis(200));
+        assert_eq!(scores.len(), 7);
+        assert!(scores.iter().all(Option::is_some));
+    }
+}
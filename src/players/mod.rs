@@ -0,0 +1,30 @@
+//! The `Player` trait and the built-in human and computer players.
+
+mod action;
+pub use action::Action;
+
+mod player;
+pub use player::Player;
+
+mod turn_context;
+pub use turn_context::TurnContext;
+
+mod ai_player;
+pub use ai_player::{AIPlayer, Difficulty};
+
+mod terminal_player;
+pub use terminal_player::TerminalPlayer;
+
+#[cfg(feature = "tui")]
+mod tui_player;
+#[cfg(feature = "tui")]
+pub use tui_player::TuiPlayer;
+
+mod minimax_player;
+pub use minimax_player::MinimaxPlayer;
+
+mod perfect_player;
+pub use perfect_player::PerfectPlayer;
+
+mod mcts_player;
+pub use mcts_player::{Budget, MctsPlayer};
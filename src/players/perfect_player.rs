@@ -0,0 +1,219 @@
+use crate::{solver, Connect4Error, MinimaxPlayer, Player, Solver, TurnContext};
+use std::time::{Duration, Instant};
+
+/// A player which plays perfectly on the standard 7x6 board using a `Solver`.
+///
+/// Among equally scored moves the one closest to the center is played. Only standard boards
+/// with a win length of 4 and two teams that alternate turns are supported (see `supports`);
+/// any other game makes `get_column_index` return an `Err`.
+///
+/// Solving positions early in the game is expensive: apart from the opening move, which is
+/// always played in the center, the first few moves can take minutes to compute. A player
+/// constructed with `PerfectPlayer::new` solves every position however long it takes, except in
+/// timed games, where the solver stops once the turn's `TurnContext::time_budget` runs out. A
+/// time limit for every move can be set with `PerfectPlayer::with_time_limit`. When the solver
+/// is stopped, the move is picked by a `MinimaxPlayer` search in whatever time remains instead
+/// (see `last_move_solved`). Positions the solver got through stay cached, so play becomes
+/// perfect as the board fills up.
+///
+/// The `Solver` and its transposition table of about 40 MB are only allocated once the
+/// player has a position to solve.
+pub struct PerfectPlayer {
+    name: String,
+    solver: Option<Solver>,
+    time_limit: Option<Duration>,
+    last_move_solved: bool,
+}
+
+impl PerfectPlayer {
+    /// Constructs a `PerfectPlayer` which takes as long as it needs to solve every move, unless
+    /// the game is timed.
+    pub fn new(name: String) -> Self {
+        PerfectPlayer {
+            name,
+            solver: None,
+            time_limit: None,
+            last_move_solved: false,
+        }
+    }
+
+    /// Constructs a `PerfectPlayer` which takes up to `time_limit` for a move.
+    pub fn with_time_limit(name: String, time_limit: Duration) -> Self {
+        PerfectPlayer {
+            time_limit: Some(time_limit),
+            ..Self::new(name)
+        }
+    }
+
+    /// Returns `true` if the last move returned by `get_column_index` was solved and therefore
+    /// perfect, or `false` if it was picked by the fallback search because the solver ran out of
+    /// time, or if the player has not moved yet.
+    pub fn last_move_solved(&self) -> bool {
+        self.last_move_solved
+    }
+
+    /// Returns `true` if the game of the turn described by `ctx` can be played perfectly: a
+    /// standard 7x6 board with a win length of 4 on which two teams alternate turns.
+    pub fn supports(ctx: &TurnContext) -> bool {
+        Self::check_supported(ctx).is_ok()
+    }
+
+    /// Returns a `Result` type with a unit `Ok` if the game of the turn described by `ctx` is
+    /// supported or an `Err` with a `Connect4Error::InvalidConfig` explaining why it is not.
+    fn check_supported(ctx: &TurnContext) -> Result<(), Connect4Error> {
+        if !solver::is_standard(ctx.board) || ctx.amount_to_win != 4 {
+            return Err(Connect4Error::InvalidConfig(
+                "Perfect play is only supported on 7x6 boards with a win length of 4.".into(),
+            ));
+        }
+        let alternating = (0..ctx.player_colors.len())
+            .all(|plies| ctx.color_after(plies) == ctx.color_after(plies + 2));
+        if !alternating || ctx.color_after(0) == ctx.color_after(1) {
+            return Err(Connect4Error::InvalidConfig(
+                "Perfect play is only supported when two teams alternate turns.".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Player for PerfectPlayer {
+    fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        Self::check_supported(ctx)?;
+        let center = ctx.board.num_columns / 2;
+        if ctx
+            .board
+            .get_data()
+            .iter()
+            .flatten()
+            .all(|item| item.is_none())
+        {
+            // the center is known to be the only winning first move
+            self.last_move_solved = true;
+            return Ok(center);
+        }
+        let start = Instant::now();
+        let time_limit = match (self.time_limit, ctx.time_budget()) {
+            (Some(time_limit), Some(budget)) => Some(time_limit.min(budget)),
+            (time_limit, budget) => time_limit.or(budget),
+        };
+        let solver = self.solver.get_or_insert_with(Solver::new);
+        let scores = match time_limit {
+            None => solver.analyze(ctx.board, ctx.color)?,
+            Some(time_limit) => {
+                match solver.analyze_until(ctx.board, ctx.color, start + time_limit)? {
+                    Some(scores) => scores,
+                    None => {
+                        self.last_move_solved = false;
+                        let time_left = time_limit.saturating_sub(start.elapsed());
+                        return MinimaxPlayer::with_time_budget(self.name.clone(), time_left)
+                            .get_column_index(ctx);
+                    }
+                }
+            }
+        };
+        self.last_move_solved = true;
+        scores
+            .iter()
+            .enumerate()
+            .filter_map(|(col_index, score)| score.map(|score| (col_index, score.rank())))
+            .max_by_key(|(col_index, rank)| (*rank, std::cmp::Reverse(col_index.abs_diff(center))))
+            .map(|(col_index, _)| col_index)
+            .ok_or(Connect4Error::BoardFull)
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Board, Color};
+
+    const COLORS: [Color; 2] = [Color::RED, Color::BLACK];
+
+    /// Returns the context of the turn after the 1-based column digits of `moves` are played.
+    fn context<'a>(board: &'a Board, moves: &str) -> TurnContext<'a> {
+        TurnContext {
+            board,
+            color: COLORS[moves.len() % 2],
+            amount_to_win: 4,
+            player_colors: &COLORS,
+            player_names: &[],
+            player_index: moves.len() % 2,
+            time_left: None,
+            clock: None,
+            draw_offer: None,
+            draw_declined_by: None,
+        }
+    }
+
+    #[test]
+    fn solved_positions_are_played_perfectly() {
+        let moves = "2252576253462244111563365343671351441";
+        let board = Board::from_moves(moves, 6, 7).unwrap();
+        let mut player = PerfectPlayer::new("Perfect".into());
+        assert!(player.solver.is_none() && !player.last_move_solved());
+        let col_index = player.get_column_index(&context(&board, moves)).unwrap();
+        assert!(player.solver.is_some() && player.last_move_solved());
+        let scores = Solver::new().analyze(&board, Color::BLACK).unwrap();
+        let best = scores.iter().flatten().map(|score| score.rank()).max();
+        assert_eq!(scores[col_index].map(|score| score.rank()), best);
+        let empty = Board::new(6, 7, 3, 7);
+        assert_eq!(player.get_column_index(&context(&empty, "")).unwrap(), 3);
+    }
+
+    #[test]
+    fn unsolved_positions_fall_back_to_a_timed_search() {
+        let board = Board::from_moves("44", 6, 7).unwrap();
+        let mut player =
+            PerfectPlayer::with_time_limit("Perfect".into(), Duration::from_millis(200));
+        let start = Instant::now();
+        let col_index = player.get_column_index(&context(&board, "44")).unwrap();
+        assert!(start.elapsed() < Duration::from_millis(600));
+        assert!(board.available_column(col_index));
+        assert!(!player.last_move_solved());
+        let empty = Board::new(6, 7, 3, 7);
+        assert_eq!(player.get_column_index(&context(&empty, "")).unwrap(), 3);
+        assert!(player.last_move_solved());
+    }
+
+    #[test]
+    fn the_time_budget_of_the_turn_is_respected() {
+        let board = Board::from_moves("44", 6, 7).unwrap();
+        let ctx = TurnContext {
+            time_left: Some(Duration::from_millis(150)),
+            ..context(&board, "44")
+        };
+        let start = Instant::now();
+        let mut player = PerfectPlayer::new("Perfect".into());
+        let col_index = player.get_column_index(&ctx).unwrap();
+        assert!(start.elapsed() < Duration::from_millis(300));
+        assert!(!player.last_move_solved());
+        assert!(board.available_column(col_index));
+    }
+
+    #[test]
+    fn only_standard_games_are_supported() {
+        let board = Board::new(6, 7, 3, 7);
+        assert!(PerfectPlayer::supports(&context(&board, "")));
+        let small = Board::new(4, 5, 3, 7);
+        assert!(!PerfectPlayer::supports(&context(&small, "")));
+        let ctx = TurnContext {
+            amount_to_win: 5,
+            ..context(&board, "")
+        };
+        assert!(!PerfectPlayer::supports(&ctx));
+        let three_teams = [Color::RED, Color::BLACK, Color::YELLOW];
+        let ctx = TurnContext {
+            player_colors: &three_teams,
+            ..context(&board, "")
+        };
+        assert!(matches!(
+            PerfectPlayer::new("Perfect".into()).get_column_index(&ctx),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+    }
+}
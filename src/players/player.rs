@@ -0,0 +1,38 @@
+use crate::{Action, Connect4Error, TurnContext};
+
+pub trait Player {
+    /// Returns the index of the column the player drops their piece in.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the column index or an `Err` with a
+    /// `Connect4Error` if the player cannot decide. Failures specific to the player, such as a
+    /// lost connection to a remote player, should be reported as `Connect4Error::Player`.
+    fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error>;
+
+    /// Returns the `Action` the player takes on their turn.
+    ///
+    /// Also called to ask the player to accept or decline a draw offered by another team, in
+    /// which case `ctx.draw_offer` holds the index of the offering player.
+    ///
+    /// Defaults to dropping a piece in the column returned by `get_column_index` and to
+    /// declining draw offers; players that can request take-backs, resign or agree to draws
+    /// should override this. Returning a `Connect4Error::Player` or
+    /// `Connect4Error::StdinClosed` error forfeits the game on behalf of the player's team (see
+    /// `EndReason::Forfeit`); any other error, or dropping a piece in an unavailable column,
+    /// stops the game with that error.
+    fn get_action(&mut self, ctx: &TurnContext) -> Result<Action, Connect4Error> {
+        if ctx.draw_offer.is_some() {
+            return Ok(Action::DeclineDraw);
+        }
+        self.get_column_index(ctx).map(Action::Drop)
+    }
+
+    fn get_name(&self) -> String;
+
+    /// Reseeds the player's random number generator with `seed` so that their moves can be
+    /// reproduced.
+    ///
+    /// Called for every player when a game is given a seed (see `GameBuilder::seed`). Defaults
+    /// to doing nothing, which suits deterministic players; players that make random choices
+    /// should override this and draw all of their randomness from the seeded generator.
+    fn set_seed(&mut self, _seed: u64) {}
+}
@@ -0,0 +1,131 @@
+use crate::{Action, Connect4Error, Player, TurnContext};
+
+/// A player who types their moves into the terminal.
+///
+/// The player only prompts for input; a game with a `TerminalPlayer` needs an observer such as
+/// `TerminalObserver` to show the board.
+pub struct TerminalPlayer {
+    name: String,
+}
+
+impl TerminalPlayer {
+    pub fn new(name: String) -> Self {
+        TerminalPlayer { name }
+    }
+
+    /// Prompts the user in the terminal until they input a valid action.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the chosen `Action` or an `Err` with
+    /// `Connect4Error::StdinClosed` if standard input ends before a valid action is read.
+    ///
+    /// Undo, redo, save, draw offer and resignation requests (`u`, `r`, `s`, `d` and `q`) are
+    /// only accepted when `allow_actions` is `true`.
+    fn prompt(&mut self, ctx: &TurnContext, allow_actions: bool) -> Result<Action, Connect4Error> {
+        let board = ctx.board;
+        let team = board.get_team(ctx.color).name;
+        let mut col_index;
+        let mut error_msg: Option<String> = None;
+        loop {
+            if let Some(msg) = error_msg.take() {
+                println!("{}", msg);
+            }
+            let mut line = String::new();
+            if allow_actions {
+                println!(
+                    "{} ({}) [u: undo, r: redo, s: save, d: offer draw, q: resign]:",
+                    team,
+                    self.get_name()
+                );
+            } else {
+                println!("{} ({}):", team, self.get_name());
+            }
+            if std::io::stdin().read_line(&mut line)? == 0 {
+                return Err(Connect4Error::StdinClosed);
+            }
+            match line.trim() {
+                "u" | "undo" if allow_actions => return Ok(Action::Undo),
+                "r" | "redo" if allow_actions => return Ok(Action::Redo),
+                "s" | "save" if allow_actions => return Ok(Action::Save),
+                "d" | "draw" if allow_actions && ctx.draw_declined_by.is_none() => {
+                    return Ok(Action::OfferDraw)
+                }
+                "d" | "draw" if allow_actions => {
+                    error_msg = Some("Drop a piece before offering another draw.".into());
+                    continue;
+                }
+                "q" | "resign" if allow_actions => return Ok(Action::Resign),
+                _ => {}
+            }
+            if let Ok(int) = line.trim().parse::<usize>() {
+                col_index = int.wrapping_sub(1);
+            } else {
+                error_msg = Some("Please input a valid integer.".into());
+                continue;
+            }
+            if board.available_column(col_index) {
+                return Ok(Action::Drop(col_index));
+            } else {
+                error_msg = Some(format!(
+                    "Please input a valid column index (1-{}).",
+                    board.num_columns
+                ));
+                continue;
+            }
+        }
+    }
+
+    /// Asks the user in the terminal whether they accept the draw offered by the player at
+    /// `offering_index` until they answer yes or no.
+    ///
+    /// Returns a `Result` type with an `Ok` containing `Action::AcceptDraw` or
+    /// `Action::DeclineDraw` or an `Err` with `Connect4Error::StdinClosed` if standard input
+    /// ends before an answer is read.
+    fn respond_to_draw(
+        &mut self,
+        ctx: &TurnContext,
+        offering_index: usize,
+    ) -> Result<Action, Connect4Error> {
+        let board = ctx.board;
+        loop {
+            println!(
+                "{} ({}): {} ({}) offers a draw. Accept? [y/n]:",
+                board.get_team(ctx.color).name,
+                self.get_name(),
+                board.get_team(ctx.player_colors[offering_index]).name,
+                ctx.player_names[offering_index]
+            );
+            let mut line = String::new();
+            if std::io::stdin().read_line(&mut line)? == 0 {
+                return Err(Connect4Error::StdinClosed);
+            }
+            match line.trim().to_lowercase().as_str() {
+                "y" | "yes" => return Ok(Action::AcceptDraw),
+                "n" | "no" => return Ok(Action::DeclineDraw),
+                _ => println!("Please answer y or n."),
+            }
+        }
+    }
+}
+
+impl Player for TerminalPlayer {
+    fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        match self.prompt(ctx, false)? {
+            Action::Drop(col_index) => Ok(col_index),
+            action => Err(Connect4Error::Player(format!(
+                "Unexpected action {:?}.",
+                action
+            ))),
+        }
+    }
+
+    fn get_action(&mut self, ctx: &TurnContext) -> Result<Action, Connect4Error> {
+        match ctx.draw_offer {
+            Some(offering_index) => self.respond_to_draw(ctx, offering_index),
+            None => self.prompt(ctx, true),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{
+    AIPlayer, Action, Color, Connect4Error, Difficulty, GameClock, MinimaxPlayer, PieceStyle,
+    Player, TurnContext,
+};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Stylize;
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, queue};
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+/// How often the screen is redrawn to count down the clock of a timed turn.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Keeps the terminal in raw mode with a hidden cursor until it is dropped, so that the terminal
+/// is restored even if drawing or reading a key fails.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> Result<Self, Connect4Error> {
+        terminal::enable_raw_mode()?;
+        let mut stdout = std::io::stdout();
+        queue!(stdout, cursor::Hide)?;
+        stdout.flush()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let mut stdout = std::io::stdout();
+        let _ = queue!(stdout, cursor::Show);
+        let _ = stdout.flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// A player who picks their moves with the keyboard in a full-screen terminal interface.
+///
+/// A cursor hovers above the board and is moved with the left and right arrow keys (or `a` and
+/// `d`) or jumped to a column with the number keys; Enter, Space or the down arrow drops a piece
+/// in the selected column. `u`, `r`, `s`, `o` and `q` request an undo, a redo, a save, a draw
+/// offer or a resignation, `h` moves the cursor to the column a strong computer player would
+/// pick, and Esc or Ctrl+C quits the game with a `Connect4Error::Player` error, which forfeits
+/// it. Draw offers from other teams are answered with `y` or `n`. A status bar shows whose turn
+/// it is, the players of every team and, in timed games, every clock, with the clock of the
+/// player to move counting down live.
+pub struct TuiPlayer {
+    name: String,
+    /// The column index the cursor was left at, which is kept between turns.
+    cursor: Option<usize>,
+    /// How the pieces on the board are drawn.
+    style: PieceStyle,
+}
+
+impl TuiPlayer {
+    /// Constructs a `TuiPlayer` with the cursor starting over the center column, which draws
+    /// discs in the colors the terminal supports (see `PieceStyle::detect`).
+    pub fn new(name: String) -> Self {
+        Self::with_style(name, PieceStyle::detect())
+    }
+
+    /// Constructs a `TuiPlayer` with the cursor starting over the center column, which draws
+    /// pieces in the given `PieceStyle`.
+    pub fn with_style(name: String, style: PieceStyle) -> Self {
+        TuiPlayer {
+            name,
+            cursor: None,
+            style,
+        }
+    }
+
+    /// Returns the status bar line describing the turn, the clocks `elapsed` into the turn and
+    /// the teams.
+    fn status_bar(&self, ctx: &TurnContext, elapsed: Duration) -> String {
+        let mut status = format!(
+            " {} ({}) to move",
+            ctx.board.get_team(ctx.color).name,
+            self.name
+        );
+        if let Some(clock) = ctx.clock {
+            let running = clock.get_clock_index(ctx.player_index);
+            for (clock_index, (owner, left)) in clock.get_clocks().into_iter().enumerate() {
+                let left = if clock_index == running {
+                    left.saturating_sub(elapsed)
+                } else {
+                    left
+                };
+                status += &format!(
+                    " | {} {}",
+                    owner.label(ctx.board, ctx.player_names),
+                    GameClock::format_time(left)
+                );
+            }
+        } else if let Some(time_left) = ctx.time_left {
+            status += &format!(
+                " | {:.1}s left",
+                time_left.saturating_sub(elapsed).as_secs_f64()
+            );
+        }
+        let mut teams: Vec<Color> = Vec::new();
+        for color in ctx.player_colors.iter() {
+            if !teams.contains(color) {
+                teams.push(*color);
+            }
+        }
+        for team in teams {
+            let names = ctx
+                .player_colors
+                .iter()
+                .zip(ctx.player_names.iter())
+                .filter(|(color, _)| **color == team)
+                .map(|(_, name)| name.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ");
+            status += &format!(" | {}: {names}", ctx.board.get_team(team).name);
+        }
+        status + " "
+    }
+
+    /// Clears the screen and draws the status bar `elapsed` into the turn, the cursor over column
+    /// `col_index`, the board, the key help and `message`.
+    fn draw(
+        &self,
+        ctx: &TurnContext,
+        elapsed: Duration,
+        col_index: usize,
+        allow_actions: bool,
+        message: &str,
+    ) -> Result<(), Connect4Error> {
+        let board = ctx.board;
+        let offset = |col_index: usize| {
+            1 + (board.column_width.saturating_sub(1)) / 2 + col_index * (board.column_width + 1)
+        };
+        let mut numbers = String::new();
+        for num in 1..(board.num_columns + 1) {
+            let num_str = num.to_string();
+            numbers += &" ".repeat(offset(num - 1).saturating_sub(numbers.len()));
+            numbers += &num_str;
+        }
+        let mut lines = vec![
+            self.status_bar(ctx, elapsed).reverse().to_string(),
+            String::new(),
+            " ".repeat(offset(col_index)) + &"v".bold().to_string(),
+        ];
+        lines.extend(board.render(self.style, &[])?.lines().map(String::from));
+        lines.push(numbers);
+        lines.push(String::new());
+        lines.push(if allow_actions {
+            "<-/-> or 1-9: move  Enter: drop  u: undo  r: redo  h: hint  s: save  o: offer draw  \
+             q: resign  Esc: quit"
+                .into()
+        } else {
+            "<-/-> or 1-9: move  Enter: drop  h: hint  Esc: quit".into()
+        });
+        lines.push(message.to_string());
+        let mut stdout = std::io::stdout();
+        queue!(
+            stdout,
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0)
+        )?;
+        for line in lines {
+            write!(stdout, "{line}\r\n")?;
+        }
+        stdout.flush()?;
+        Ok(())
+    }
+
+    /// Returns the column a strong computer player would pick in the position of `ctx`.
+    ///
+    /// In a timed game the search stays within the turn's `TurnContext::time_budget`, since the
+    /// time spent on the hint is charged to the user's clock.
+    fn hint(&self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        match ctx.time_budget() {
+            Some(budget) => {
+                MinimaxPlayer::with_time_budget(self.name.clone(), budget).get_column_index(ctx)
+            }
+            None => {
+                AIPlayer::with_difficulty(self.name.clone(), Difficulty::Hard).get_column_index(ctx)
+            }
+        }
+    }
+
+    /// Lets the user pick an action with the keyboard until they choose a valid one.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the chosen `Action` or an `Err` with a
+    /// `Connect4Error` if the terminal fails or the user quits.
+    ///
+    /// Undo, redo, save, draw offer and resignation requests are only accepted when
+    /// `allow_actions` is `true`.
+    fn prompt(&mut self, ctx: &TurnContext, allow_actions: bool) -> Result<Action, Connect4Error> {
+        let board = ctx.board;
+        if board.is_full() {
+            return Err(Connect4Error::BoardFull);
+        }
+        let mut col_index = self
+            .cursor
+            .filter(|col_index| *col_index < board.num_columns)
+            .unwrap_or(board.num_columns / 2);
+        let declined_message = ctx.draw_declined_by.map(|declining_index| {
+            format!(
+                "{} ({}) declined the draw.",
+                board.get_team(ctx.player_colors[declining_index]).name,
+                ctx.player_names[declining_index]
+            )
+        });
+        let mut message = declined_message.clone().unwrap_or_default();
+        let _raw_mode = RawMode::enable()?;
+        let start = Instant::now();
+        let action = loop {
+            self.draw(ctx, start.elapsed(), col_index, allow_actions, &message)?;
+            if let Some(time_left) = ctx.time_left {
+                if start.elapsed() >= time_left {
+                    // The game flags the loss on time whatever is played.
+                    let col_index = (0..board.num_columns)
+                        .find(|col_index| board.available_column(*col_index))
+                        .ok_or(Connect4Error::BoardFull)?;
+                    break Some(Action::Drop(col_index));
+                }
+                if !event::poll(REDRAW_INTERVAL)? {
+                    continue;
+                }
+            }
+            let key = match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release => key,
+                _ => continue,
+            };
+            message.clear();
+            match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    break None;
+                }
+                KeyCode::Esc => break None,
+                KeyCode::Left | KeyCode::Char('a') => {
+                    col_index = col_index.checked_sub(1).unwrap_or(board.num_columns - 1);
+                }
+                KeyCode::Right | KeyCode::Char('d') => {
+                    col_index = (col_index + 1) % board.num_columns;
+                }
+                KeyCode::Char(digit @ '1'..='9') => {
+                    let target = digit as usize - '1' as usize;
+                    if target < board.num_columns {
+                        col_index = target;
+                    }
+                }
+                KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Down => {
+                    if board.available_column(col_index) {
+                        break Some(Action::Drop(col_index));
+                    }
+                    message = format!("Column {} is full.", col_index + 1);
+                }
+                KeyCode::Char('h') => {
+                    message = "Thinking...".into();
+                    self.draw(ctx, start.elapsed(), col_index, allow_actions, &message)?;
+                    col_index = self.hint(ctx)?;
+                    message = format!("Hint: column {}.", col_index + 1);
+                }
+                KeyCode::Char('u') if allow_actions => break Some(Action::Undo),
+                KeyCode::Char('r') if allow_actions => break Some(Action::Redo),
+                KeyCode::Char('s') if allow_actions => break Some(Action::Save),
+                KeyCode::Char('o') if allow_actions => match &declined_message {
+                    Some(declined_message) => {
+                        message = format!("{declined_message} Drop a piece before offering again.");
+                    }
+                    None => break Some(Action::OfferDraw),
+                },
+                KeyCode::Char('q') if allow_actions => break Some(Action::Resign),
+                _ => {}
+            }
+        };
+        self.cursor = Some(col_index);
+        action.ok_or_else(|| Connect4Error::Player(format!("{} quit the game.", self.name)))
+    }
+
+    /// Asks the user whether they accept the draw offered by the player at `offering_index`
+    /// until they press `y` or `n`.
+    ///
+    /// Returns a `Result` type with an `Ok` containing `Action::AcceptDraw` or
+    /// `Action::DeclineDraw` or an `Err` with a `Connect4Error` if the terminal fails or the
+    /// user quits.
+    fn respond_to_draw(
+        &mut self,
+        ctx: &TurnContext,
+        offering_index: usize,
+    ) -> Result<Action, Connect4Error> {
+        let col_index = self
+            .cursor
+            .filter(|col_index| *col_index < ctx.board.num_columns)
+            .unwrap_or(ctx.board.num_columns / 2);
+        let message = format!(
+            "{} ({}) offers a draw. y: accept  n: decline",
+            ctx.board.get_team(ctx.player_colors[offering_index]).name,
+            ctx.player_names[offering_index]
+        );
+        let _raw_mode = RawMode::enable()?;
+        loop {
+            self.draw(ctx, Duration::ZERO, col_index, false, &message)?;
+            let key = match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release => key,
+                _ => continue,
+            };
+            match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+                KeyCode::Esc => break,
+                KeyCode::Char('y') => return Ok(Action::AcceptDraw),
+                KeyCode::Char('n') => return Ok(Action::DeclineDraw),
+                _ => {}
+            }
+        }
+        Err(Connect4Error::Player(format!(
+            "{} quit the game.",
+            self.name
+        )))
+    }
+}
+
+impl Player for TuiPlayer {
+    fn get_column_index(&mut self, ctx: &TurnContext) -> Result<usize, Connect4Error> {
+        match self.prompt(ctx, false)? {
+            Action::Drop(col_index) => Ok(col_index),
+            action => Err(Connect4Error::Player(format!(
+                "Unexpected action {:?}.",
+                action
+            ))),
+        }
+    }
+
+    fn get_action(&mut self, ctx: &TurnContext) -> Result<Action, Connect4Error> {
+        match ctx.draw_offer {
+            Some(offering_index) => self.respond_to_draw(ctx, offering_index),
+            None => self.prompt(ctx, true),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Board, Color, GameClock, TimeControl};
+use std::time::Duration;
+
+/// The information about the game state a `Player` is given when it is their turn.
+#[derive(Debug, Clone, Copy)]
+pub struct TurnContext<'a> {
+    /// The `Board` the game is being played on.
+    pub board: &'a Board,
+    /// The `Color` of the team the active player is on.
+    pub color: Color,
+    /// The number of pieces a team must get in a row to win the game.
+    pub amount_to_win: usize,
+    /// The color team of every player in the game in turn order.
+    pub player_colors: &'a [Color],
+    /// The name of every player in the game in turn order.
+    pub player_names: &'a [String],
+    /// The index of the active player in `player_colors`.
+    pub player_index: usize,
+    /// The time the active player has left to decide on their move, or `None` if the game is
+    /// not timed.
+    pub time_left: Option<Duration>,
+    /// The clocks of every player or team, or `None` if the game is not timed.
+    pub clock: Option<&'a GameClock>,
+    /// The index of the player who offered a draw if the active player is being asked to
+    /// accept or decline it with `Action::AcceptDraw` or `Action::DeclineDraw` rather than to
+    /// take their turn, or `None` otherwise.
+    pub draw_offer: Option<usize>,
+    /// The index of the player who declined the draw the active player offered earlier in their
+    /// turn, or `None` if they have not had an offer declined since they last dropped a piece.
+    /// The game ignores further offers until then.
+    pub draw_declined_by: Option<usize>,
+}
+
+impl TurnContext<'_> {
+    /// The time `time_budget` always leaves unused, to cover the time it takes to return a move
+    /// after deciding on it.
+    pub const SAFETY_MARGIN: Duration = Duration::from_millis(50);
+
+    /// Returns the `Color` of the player who moves `plies` turns after the active player.
+    pub fn color_after(&self, plies: usize) -> Color {
+        self.player_colors[(self.player_index + plies) % self.player_colors.len()]
+    }
+
+    /// Returns the time the active player can spend on this move without risking running out
+    /// of time later in the game, or `None` if the game is not timed.
+    ///
+    /// Under a per-move limit this is all of `time_left`. Otherwise the time left is spread
+    /// over the moves the player may still have to make, assuming the board fills up, and the
+    /// increment they get back for this move is added on top. Either way the budget is at least
+    /// `SAFETY_MARGIN` shorter than `time_left`, so a player who stops thinking once it is used up
+    /// does not run out of time.
+    pub fn time_budget(&self) -> Option<Duration> {
+        let time_left = self.time_left?.saturating_sub(Self::SAFETY_MARGIN);
+        let control = match self.clock.map(GameClock::get_control) {
+            None | Some(TimeControl::PerMove { .. }) => return Some(time_left),
+            Some(control) => control,
+        };
+        let empty_cells = self
+            .board
+            .get_data()
+            .iter()
+            .flatten()
+            .filter(|cell| cell.is_none())
+            .count();
+        let moves_left = empty_cells.div_ceil(self.player_colors.len()).max(1) as u32;
+        Some((time_left / moves_left + control.increment()).min(time_left))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClockScope;
+
+    const COLORS: [Color; 2] = [Color::RED, Color::BLACK];
+
+    /// Returns the time budget of the first turn on `board` under `control` with `time_left`.
+    fn budget(board: &Board, control: TimeControl, time_left: Duration) -> Option<Duration> {
+        let clock = GameClock::new(control, ClockScope::Player, &COLORS);
+        TurnContext {
+            board,
+            color: Color::RED,
+            amount_to_win: 4,
+            player_colors: &COLORS,
+            player_names: &[],
+            player_index: 0,
+            time_left: Some(time_left),
+            clock: Some(&clock),
+            draw_offer: None,
+            draw_declined_by: None,
+        }
+        .time_budget()
+    }
+
+    #[test]
+    fn budgets_keep_a_safety_margin() {
+        let board = Board::new(6, 7, 3, 7);
+        let limit = Duration::from_secs(2);
+        let per_move = TimeControl::PerMove { limit };
+        assert_eq!(
+            budget(&board, per_move, limit),
+            Some(limit - TurnContext::SAFETY_MARGIN)
+        );
+        assert_eq!(
+            budget(&board, per_move, Duration::from_millis(10)),
+            Some(Duration::ZERO)
+        );
+        let almost_full =
+            Board::from_moves("1111112222223333334444445555556666667777", 6, 7).unwrap();
+        let sudden = TimeControl::SuddenDeath { initial: limit };
+        assert_eq!(
+            budget(&almost_full, sudden, limit),
+            Some(limit - TurnContext::SAFETY_MARGIN)
+        );
+    }
+
+    #[test]
+    fn budgets_spread_the_time_over_the_remaining_moves() {
+        let board = Board::new(6, 7, 3, 7);
+        let initial = Duration::from_secs(210);
+        let sudden = TimeControl::SuddenDeath { initial };
+        let expected = (initial - TurnContext::SAFETY_MARGIN) / 21;
+        assert_eq!(budget(&board, sudden, initial), Some(expected));
+        let fischer = TimeControl::Fischer {
+            initial,
+            increment: Duration::from_secs(2),
+        };
+        assert_eq!(
+            budget(&board, fischer, initial),
+            Some(expected + Duration::from_secs(2))
+        );
+    }
+}
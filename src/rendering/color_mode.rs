@@ -0,0 +1,146 @@
+use std::io::IsTerminal;
+
+/// The colors a terminal can show, from none at all to 24-bit true color.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ColorMode {
+    /// No colors; pieces are told apart by their symbols.
+    NoColor,
+    /// The 16 basic ANSI colors.
+    Ansi16,
+    /// The 256 color xterm palette.
+    Ansi256,
+    /// 24-bit RGB colors.
+    TrueColor,
+}
+
+/// The RGB values of the 16 basic ANSI colors as shown by xterm.
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The intensities of the 6 levels of each channel of the xterm 256 color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Returns the squared distance between two RGB colors.
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+/// Returns the index of the level in `CUBE_LEVELS` closest to `value`.
+fn cube_level(value: u8) -> usize {
+    (0..CUBE_LEVELS.len())
+        .min_by_key(|i| (CUBE_LEVELS[*i] as i32 - value as i32).abs())
+        .unwrap_or(0)
+}
+
+impl ColorMode {
+    /// Returns the `ColorMode` the terminal attached to standard output supports.
+    ///
+    /// Colors are turned off if the `NO_COLOR` environment variable is set to anything but an
+    /// empty string (see <https://no-color.org>), if standard output is not a terminal or if
+    /// `TERM` is unset or `dumb`. Otherwise `COLORTERM` set to `truecolor` or `24bit` enables
+    /// true color and a `TERM` containing `256color` the 256 color palette, falling back to the
+    /// 16 basic colors.
+    pub fn detect() -> Self {
+        let var = |name: &str| std::env::var(name).unwrap_or_default();
+        if !var("NO_COLOR").is_empty() || !std::io::stdout().is_terminal() {
+            return ColorMode::NoColor;
+        }
+        let term = var("TERM");
+        if term.is_empty() || term == "dumb" {
+            ColorMode::NoColor
+        } else if matches!(var("COLORTERM").as_str(), "truecolor" | "24bit") {
+            ColorMode::TrueColor
+        } else if term.contains("256color") {
+            ColorMode::Ansi256
+        } else {
+            ColorMode::Ansi16
+        }
+    }
+
+    /// Returns `text` wrapped in the escape codes which show it in the RGB color `rgb`, using
+    /// the closest color the mode supports, or `text` unchanged for `NoColor`.
+    pub fn paint(&self, text: &str, rgb: (u8, u8, u8)) -> String {
+        let (r, g, b) = rgb;
+        let code = match self {
+            ColorMode::NoColor => return text.to_string(),
+            ColorMode::Ansi16 => {
+                let index = (0..ANSI16_PALETTE.len())
+                    .min_by_key(|i| distance(ANSI16_PALETTE[*i], rgb))
+                    .unwrap_or(7);
+                if index < 8 {
+                    (30 + index).to_string()
+                } else {
+                    (90 + index - 8).to_string()
+                }
+            }
+            ColorMode::Ansi256 => {
+                let (ri, gi, bi) = (cube_level(r), cube_level(g), cube_level(b));
+                let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+                // the grayscale ramp runs from 8 to 238 in steps of 10
+                let gray_value = (r as u32 + g as u32 + b as u32) / 3;
+                let gray_index = (gray_value.saturating_sub(3) / 10).min(23);
+                let gray = (8 + 10 * gray_index) as u8;
+                let index = if distance((gray, gray, gray), rgb) < distance(cube, rgb) {
+                    232 + gray_index as usize
+                } else {
+                    16 + 36 * ri + 6 * gi + bi
+                };
+                format!("38;5;{index}")
+            }
+            ColorMode::TrueColor => format!("38;2;{r};{g};{b}"),
+        };
+        format!("\x1b[{code}m{text}\x1b[39m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    #[test]
+    fn colors_are_painted_in_the_closest_supported_color() {
+        let red = Color::RED.rgb();
+        assert_eq!(ColorMode::NoColor.paint("x", red), "x");
+        assert_eq!(ColorMode::Ansi16.paint("x", red), "\x1b[31mx\x1b[39m");
+        assert_eq!(
+            ColorMode::Ansi256.paint("x", red),
+            "\x1b[38;5;160mx\x1b[39m"
+        );
+        assert_eq!(
+            ColorMode::TrueColor.paint("x", red),
+            "\x1b[38;2;220;40;40mx\x1b[39m"
+        );
+    }
+
+    #[test]
+    fn grays_use_the_bright_colors_and_the_grayscale_ramp() {
+        let black = Color::BLACK.rgb();
+        assert_eq!(ColorMode::Ansi16.paint("x", black), "\x1b[90mx\x1b[39m");
+        assert_eq!(
+            ColorMode::Ansi256.paint("x", black),
+            "\x1b[38;5;243mx\x1b[39m"
+        );
+        assert_eq!(
+            ColorMode::Ansi256.paint("x", (255, 255, 255)),
+            "\x1b[38;5;231mx\x1b[39m"
+        );
+    }
+}
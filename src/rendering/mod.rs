@@ -0,0 +1,13 @@
+//! Output of boards and games to the terminal.
+
+mod clear_screen;
+pub use clear_screen::clear_screen;
+
+mod terminal_observer;
+pub use terminal_observer::TerminalObserver;
+
+mod color_mode;
+pub use color_mode::ColorMode;
+
+mod text;
+pub use text::PieceStyle;
@@ -0,0 +1,267 @@
+use crate::{clear_screen, Board, Connect4Error, GameEvent, GameObserver, GameOutcome, PieceStyle};
+
+/// The terminal output of a game: redraws the board with its column numbers before every turn
+/// along with the clocks of timed games, and prints the final board and result once the game
+/// ends.
+///
+/// Games print nothing on their own; attach a `TerminalObserver` with `GameBuilder::observer` or
+/// `Game::add_observer` to show a game in the terminal.
+///
+/// # Examples
+///
+/// ```no_run
+/// use connect_4::{AIPlayer, Color, Game, TerminalObserver, TerminalPlayer};
+///
+/// # fn main() -> Result<(), connect_4::Connect4Error> {
+/// let mut game = Game::builder()
+///     .player(Box::new(TerminalPlayer::new("Player 1".into())), Color::RED)
+///     .player(Box::new(AIPlayer::new("Bot 1".into())), Color::BLACK)
+///     .observer(Box::new(TerminalObserver::new()))
+///     .build()?;
+/// game.start()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TerminalObserver {
+    /// How the pieces on the board are drawn.
+    style: PieceStyle,
+    /// A message printed under the board when it is redrawn for the next turn, such as a
+    /// declined draw offer or the reason an action was rejected.
+    notice: Option<String>,
+}
+
+impl Default for TerminalObserver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TerminalObserver {
+    /// Constructs a `TerminalObserver` which draws discs in the colors the terminal supports
+    /// (see `PieceStyle::detect`).
+    pub fn new() -> Self {
+        Self::with_style(PieceStyle::detect())
+    }
+
+    /// Constructs a `TerminalObserver` which draws pieces in the given `PieceStyle`.
+    pub fn with_style(style: PieceStyle) -> Self {
+        TerminalObserver {
+            style,
+            notice: None,
+        }
+    }
+
+    /// Returns the `PieceStyle` pieces are drawn in.
+    pub fn get_style(&self) -> PieceStyle {
+        self.style
+    }
+
+    /// Returns the line of 1-based column numbers printed under `board`, each centered under
+    /// its column.
+    fn column_numbers(board: &Board) -> String {
+        let mut index_str =
+            " ".repeat(1 + ((board.column_width as f64 - 1.0) / 2.0).floor() as usize);
+        for num_str in (1..(board.num_columns + 1)).map(|num| num.to_string()) {
+            index_str = index_str
+                + &num_str
+                + &" ".repeat((board.column_width + 1).saturating_sub(num_str.len()));
+        }
+        index_str
+    }
+
+    /// Returns `names` joined into a readable list.
+    fn format_names(names: &[String]) -> String {
+        let num_names = names.len();
+        names
+            .iter()
+            .enumerate()
+            .fold(String::new(), |acc, (i, name)| {
+                acc + if i == 0 {
+                    ""
+                } else if num_names == 2 {
+                    " and "
+                } else if i == num_names - 1 {
+                    ", and "
+                } else {
+                    ", "
+                } + name
+            })
+    }
+
+    /// Prints which team won `outcome`, if any, by the name it has on `board`.
+    fn print_winner(board: &Board, outcome: &GameOutcome) {
+        if let Some(color) = outcome.winning_color {
+            let team = board.get_team(color).name;
+            let winners_str = Self::format_names(&outcome.winner_names);
+            if outcome.winner_names.len() == 1 {
+                println!("{team} ({winners_str}) wins!");
+            } else {
+                println!("{team} team ({winners_str}) wins!");
+            }
+        }
+    }
+}
+
+impl GameObserver for TerminalObserver {
+    fn on_event(&mut self, event: &GameEvent) -> Result<(), Connect4Error> {
+        match *event {
+            GameEvent::Started {
+                board,
+                player_colors,
+                ..
+            } => {
+                // Fails before the first turn if the pieces of a team cannot be drawn.
+                if let PieceStyle::Words = self.style {
+                    for color in player_colors {
+                        let required = board.get_team(*color).name.chars().count();
+                        if required > board.column_width {
+                            return Err(Connect4Error::InadequateColumnWidth {
+                                column_width: board.column_width,
+                                required,
+                            });
+                        }
+                    }
+                }
+            }
+            GameEvent::TurnChanged {
+                board,
+                player_names,
+                clock,
+                ..
+            } => {
+                clear_screen();
+                board.print_styled(self.style, &[])?;
+                println!("{}", Self::column_numbers(board));
+                if let Some(clock) = clock {
+                    println!("{}", clock.format_clocks(board, player_names, "  "));
+                }
+                if let Some(notice) = self.notice.take() {
+                    println!("{notice}");
+                }
+            }
+            GameEvent::Won {
+                board,
+                outcome,
+                winning_cells,
+            } => {
+                clear_screen();
+                board.print_styled(self.style, winning_cells)?;
+                Self::print_winner(board, outcome);
+            }
+            GameEvent::Tie { board, .. } => {
+                clear_screen();
+                board.print_styled(self.style, &[])?;
+                println!("Tie.");
+            }
+            GameEvent::Resigned {
+                board,
+                color,
+                player_name,
+                outcome,
+                ..
+            } => {
+                clear_screen();
+                board.print_styled(self.style, &[])?;
+                println!("{} ({player_name}) resigns.", board.get_team(color).name);
+                Self::print_winner(board, outcome);
+            }
+            GameEvent::DrawOffered {
+                board,
+                color,
+                player_name,
+                ..
+            } => {
+                println!(
+                    "{} ({player_name}) offers a draw.",
+                    board.get_team(color).name
+                );
+            }
+            GameEvent::DrawDeclined {
+                board,
+                color,
+                player_name,
+                ..
+            } => {
+                self.notice = Some(format!(
+                    "{} ({player_name}) declines the draw.",
+                    board.get_team(color).name
+                ));
+            }
+            GameEvent::ActionRejected { reason, .. } => self.notice = Some(reason.to_string()),
+            GameEvent::Drawn { board, .. } => {
+                clear_screen();
+                board.print_styled(self.style, &[])?;
+                println!("Draw agreed.");
+            }
+            GameEvent::Forfeited {
+                board,
+                color,
+                player_name,
+                error,
+                outcome,
+                ..
+            } => {
+                clear_screen();
+                board.print_styled(self.style, &[])?;
+                println!(
+                    "{} ({player_name}) forfeits: {error}",
+                    board.get_team(color).name
+                );
+                Self::print_winner(board, outcome);
+            }
+            GameEvent::TimedOut {
+                board,
+                color,
+                player_name,
+                outcome,
+                ..
+            } => {
+                clear_screen();
+                board.print_styled(self.style, &[])?;
+                println!(
+                    "{} ({player_name}) ran out of time.",
+                    board.get_team(color).name
+                );
+                Self::print_winner(board, outcome);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, ColorMode, Team};
+
+    /// Returns the event of a game between Red and Black starting on `board`.
+    fn started(board: &Board) -> GameEvent<'_> {
+        GameEvent::Started {
+            board,
+            player_colors: &[Color::RED, Color::BLACK],
+            starting_player_index: 0,
+        }
+    }
+
+    #[test]
+    fn words_must_fit_in_a_column() {
+        let mut board = Board::new(6, 7, 3, 5);
+        assert!(TerminalObserver::with_style(PieceStyle::Words)
+            .on_event(&started(&board))
+            .is_ok());
+        board.set_team(Color::BLACK, Team::new("Ravens".into(), 'V', (0, 0, 0)));
+        assert!(matches!(
+            TerminalObserver::with_style(PieceStyle::Words).on_event(&started(&board)),
+            Err(Connect4Error::InadequateColumnWidth {
+                column_width: 5,
+                required: 6,
+            })
+        ));
+        let discs = PieceStyle::Discs(ColorMode::NoColor);
+        assert!(TerminalObserver::with_style(discs)
+            .on_event(&started(&board))
+            .is_ok());
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{Board, Color, ColorMode, Connect4Error};
+
+/// The character discs are drawn with.
+const DISC: &str = "\u{25cf}";
+
+/// How the pieces in a rendered `Board` are drawn.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PieceStyle {
+    /// The name of the piece's `Team`, e.g. `Red`, which needs a column width as wide as the
+    /// longest name in the game, e.g. 5 for `Red` and `Black`.
+    Words,
+    /// A disc in the RGB value of the piece's `Team`, or the team's glyph without colors, which
+    /// fits in a column width of 1.
+    Discs(ColorMode),
+}
+
+impl PieceStyle {
+    /// Returns discs in the `ColorMode` the terminal supports (see `ColorMode::detect`).
+    pub fn detect() -> Self {
+        PieceStyle::Discs(ColorMode::detect())
+    }
+}
+
+/// Returns `text` with the case of its letters swapped, or as many `*` as it has characters if
+/// it has no cased letters, to mark a highlighted piece without escape codes.
+fn mark_plainly(text: &str) -> String {
+    let swapped = text
+        .chars()
+        .flat_map(|c| {
+            if c.is_uppercase() {
+                c.to_lowercase().collect::<Vec<char>>()
+            } else {
+                c.to_uppercase().collect::<Vec<char>>()
+            }
+        })
+        .collect::<String>();
+    if swapped == text {
+        "*".repeat(text.chars().count())
+    } else {
+        swapped
+    }
+}
+
+/// Renders boards as the boxed text grid printed by the terminal game.
+impl Board {
+    /// Returns a `Result` type with an `Ok` containing a `String` representing the board state
+    /// in a pretty printed format or an `Err` with `Connect4Error::InadequateColumnWidth` if a
+    /// piece does not fit in a column.
+    pub fn stringify(&self) -> Result<String, Connect4Error> {
+        self.stringify_highlighted(&[])
+    }
+
+    /// Returns a `Result` type with an `Ok` containing a `String` representing the board state
+    /// in a pretty printed format with the pieces in the `(row_index, col_index)` cells of
+    /// `highlighted` marked, or an `Err` with `Connect4Error::InadequateColumnWidth` if a piece
+    /// does not fit in a column.
+    ///
+    /// Highlighted pieces are wrapped in brackets if the column width allows for it and shown
+    /// with the case of their letters swapped otherwise (see `Board::render`).
+    pub fn stringify_highlighted(
+        &self,
+        highlighted: &[(usize, usize)],
+    ) -> Result<String, Connect4Error> {
+        self.render(PieceStyle::Words, highlighted)
+    }
+
+    /// Returns a `Result` type with an `Ok` containing a `String` representing the board state
+    /// with pieces drawn in the given `PieceStyle` and the pieces in the `(row_index, col_index)`
+    /// cells of `highlighted` marked, or an `Err` with `Connect4Error::InadequateColumnWidth` if
+    /// a piece does not fit in a column.
+    ///
+    /// Discs take up a single character, so boards drawn with discs can be as compact as a
+    /// column width of 1. Highlighted pieces are wrapped in brackets if the column width allows
+    /// for it. Otherwise colored discs are shown in inverse video, and pieces drawn without
+    /// colors have the case of their letters swapped, or are replaced by `*` if that changes
+    /// nothing, so that no escape codes are written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::rendering::{ColorMode, PieceStyle};
+    /// use connect_4::{Board, Color};
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let mut board = Board::new(2, 3, 1, 1);
+    /// board.drop_piece(Color::RED, 0)?;
+    /// board.drop_piece(Color::BLACK, 2)?;
+    /// let text = board.render(PieceStyle::Discs(ColorMode::NoColor), &[])?;
+    /// assert_eq!(text, "-------\n| | | |\n-------\n|R| |B|\n-------");
+    /// let text = board.render(PieceStyle::Discs(ColorMode::NoColor), &[(1, 0)])?;
+    /// assert_eq!(text, "-------\n| | | |\n-------\n|r| |B|\n-------");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render(
+        &self,
+        style: PieceStyle,
+        highlighted: &[(usize, usize)],
+    ) -> Result<String, Connect4Error> {
+        let get_str_of = |item: Option<Color>, highlight: bool| -> Result<String, Connect4Error> {
+            if let Some(color) = item {
+                let team = self.get_team(color);
+                let (mut color_str, mut width) = match style {
+                    PieceStyle::Words => (team.name.clone(), team.name.chars().count()),
+                    PieceStyle::Discs(ColorMode::NoColor) => (team.glyph.to_string(), 1),
+                    PieceStyle::Discs(color_mode) => (color_mode.paint(DISC, team.rgb), 1),
+                };
+                if width > self.column_width {
+                    return Err(Connect4Error::InadequateColumnWidth {
+                        column_width: self.column_width,
+                        required: width,
+                    });
+                }
+                let cramped = highlight && width + 2 > self.column_width;
+                let inverse = cramped
+                    && matches!(style, PieceStyle::Discs(mode) if mode != ColorMode::NoColor);
+                if highlight && !cramped {
+                    color_str = format!("[{color_str}]");
+                    width += 2;
+                } else if cramped && !inverse {
+                    color_str = mark_plainly(&color_str);
+                }
+                let remaining = self.column_width - width;
+                let left = " ".repeat((remaining as f64 / 2.0).floor() as usize);
+                let right = " ".repeat((remaining as f64 / 2.0).ceil() as usize);
+                if inverse {
+                    Ok(left + "\x1b[7m" + &color_str + "\x1b[0m" + &right)
+                } else {
+                    Ok(left + &color_str + &right)
+                }
+            } else {
+                Ok(" ".repeat(self.column_width))
+            }
+        };
+
+        let mut bstr = "-"
+            .repeat(self.num_columns * (self.column_width + 1) + 1)
+            .to_string()
+            + "\n";
+        for (row_index, row) in self.get_data().iter().enumerate() {
+            let mut item_strs = Vec::new();
+            for (col_index, item) in row.iter().enumerate() {
+                let highlight = highlighted.contains(&(row_index, col_index));
+                item_strs.push(get_str_of(*item, highlight)?);
+            }
+            bstr = bstr
+                + &(("|".to_string()
+                    + &(" ".repeat(self.column_width) + "|").repeat(self.num_columns)
+                    + "\n")
+                    .repeat(((self.row_height - 1) as f64 / 2.0).floor() as usize));
+            bstr = bstr
+                + &item_strs
+                    .iter()
+                    .fold("|".to_string(), |acc, elem| acc + elem + "|")
+                + "\n";
+            bstr = bstr
+                + &(("|".to_string()
+                    + &(" ".repeat(self.column_width) + "|").repeat(self.num_columns)
+                    + "\n")
+                    .repeat(((self.row_height - 1) as f64 / 2.0).ceil() as usize));
+            bstr = bstr
+                + &"-"
+                    .repeat(self.num_columns * (self.column_width + 1) + 1)
+                    .to_string()
+                + "\n";
+        }
+        Ok(bstr.trim().into())
+    }
+
+    /// Pretty prints a representation of the game state to the terminal.
+    ///
+    /// Returns a `Result` type with a unit `Ok` indicating success or an `Err` with a
+    /// `Connect4Error` if the board cannot be printed.
+    pub fn print(&self) -> Result<(), Connect4Error> {
+        println!("{}", self.stringify()?);
+        Ok(())
+    }
+
+    /// Pretty prints a representation of the game state with pieces drawn in the given
+    /// `PieceStyle` to the terminal with the pieces in the `(row_index, col_index)` cells of
+    /// `highlighted` marked (see `Board::render`).
+    ///
+    /// Returns a `Result` type with a unit `Ok` indicating success or an `Err` with a
+    /// `Connect4Error` if the board cannot be printed.
+    pub fn print_styled(
+        &self,
+        style: PieceStyle,
+        highlighted: &[(usize, usize)],
+    ) -> Result<(), Connect4Error> {
+        println!("{}", self.render(style, highlighted)?);
+        Ok(())
+    }
+
+    /// Pretty prints a representation of the game state to the terminal with the pieces in the
+    /// `(row_index, col_index)` cells of `highlighted` marked (see `Board::stringify_highlighted`).
+    ///
+    /// Returns a `Result` type with a unit `Ok` indicating success or an `Err` with a
+    /// `Connect4Error` if the board cannot be printed.
+    pub fn print_highlighted(&self, highlighted: &[(usize, usize)]) -> Result<(), Connect4Error> {
+        println!("{}", self.stringify_highlighted(highlighted)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a 2x3 board with a red piece in the first and a black piece in the last column.
+    fn board(row_height: usize, column_width: usize) -> Board {
+        let mut board = Board::new(2, 3, row_height, column_width);
+        board.drop_piece(Color::RED, 0).unwrap();
+        board.drop_piece(Color::BLACK, 2).unwrap();
+        board
+    }
+
+    #[test]
+    fn pieces_are_drawn_in_the_given_style() {
+        let words = board(1, 5).render(PieceStyle::Words, &[]).unwrap();
+        assert_eq!(
+            words,
+            "-------------------\n\
+             |     |     |     |\n\
+             -------------------\n\
+             | Red |     |Black|\n\
+             -------------------"
+        );
+        let discs = board(1, 3)
+            .render(PieceStyle::Discs(ColorMode::TrueColor), &[])
+            .unwrap();
+        assert_eq!(
+            discs.lines().nth(3).unwrap(),
+            "| \x1b[38;2;220;40;40m\u{25cf}\x1b[39m |   | \x1b[38;2;120;120;120m\u{25cf}\x1b[39m |"
+        );
+        assert!(matches!(
+            board(3, 1).stringify(),
+            Err(Connect4Error::InadequateColumnWidth {
+                column_width: 1,
+                required: 3,
+            })
+        ));
+    }
+
+    #[test]
+    fn highlighted_pieces_are_bracketed_or_marked() {
+        let style = PieceStyle::Discs(ColorMode::NoColor);
+        let bracketed = board(1, 3).render(style, &[(1, 0)]).unwrap();
+        assert_eq!(bracketed.lines().nth(3).unwrap(), "|[R]|   | B |");
+        let swapped = board(1, 1).render(style, &[(1, 2)]).unwrap();
+        assert_eq!(swapped.lines().nth(3).unwrap(), "|R| |b|");
+        let words = board(1, 5).render(PieceStyle::Words, &[(1, 0)]).unwrap();
+        assert_eq!(words.lines().nth(3).unwrap(), "|[Red]|     |Black|");
+        let words = board(1, 5).render(PieceStyle::Words, &[(1, 2)]).unwrap();
+        assert_eq!(words.lines().nth(3).unwrap(), "| Red |     |bLACK|");
+        let discs = PieceStyle::Discs(ColorMode::Ansi16);
+        let inverted = board(1, 1).render(discs, &[(1, 0)]).unwrap();
+        assert!(inverted
+            .lines()
+            .nth(3)
+            .unwrap()
+            .starts_with("|\x1b[7m\x1b[31m\u{25cf}\x1b[39m\x1b[0m|"));
+    }
+
+    #[test]
+    fn plain_marks_swap_the_case_or_use_stars() {
+        assert_eq!(mark_plainly("Red"), "rED");
+        assert_eq!(mark_plainly("x"), "X");
+        assert_eq!(mark_plainly("\u{2605}"), "*");
+        assert_eq!(mark_plainly("12"), "**");
+    }
+}
@@ -0,0 +1,274 @@
+//! Running many games between computer players without any terminal output and collecting
+//! statistics about them.
+
+mod report;
+pub use report::{GameLog, SeatStats, SimulationReport};
+
+use crate::{ClockScope, Color, Connect4Error, GameBuilder, Player, TimeControl};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Constructs the player of a seat for a single game.
+type PlayerFactory = Box<dyn FnMut() -> Box<dyn Player>>;
+
+/// A seat of a `Simulation`.
+struct Seat {
+    color: Color,
+    factory: PlayerFactory,
+}
+
+/// Plays a number of headless games between computer players and summarizes the results in a
+/// `SimulationReport`.
+///
+/// Every seat is given a factory which constructs a fresh player for each game. Each game is
+/// seeded with a seed derived from the master seed of the simulation (see `GameBuilder::seed`),
+/// so a run with the same seed and seats plays exactly the same games as long as the players
+/// draw their randomness from the seed given to `Player::set_seed`. Unless a starting seat is
+/// set, the seat which moves first rotates from game to game.
+///
+/// # Examples
+///
+/// ```
+/// use connect_4::simulation::Simulation;
+/// use connect_4::{AIPlayer, Color, Difficulty, Player};
+///
+/// # fn main() -> Result<(), connect_4::Connect4Error> {
+/// let bot = |name: &'static str| {
+///     move || -> Box<dyn Player> {
+///         Box::new(AIPlayer::with_difficulty(name.into(), Difficulty::Easy))
+///     }
+/// };
+/// let report = Simulation::new()
+///     .games(20)
+///     .seed(7)
+///     .seat(Color::RED, bot("Red bot"))
+///     .seat(Color::BLACK, bot("Black bot"))
+///     .run()?;
+/// assert_eq!(report.games.len(), 20);
+/// assert_eq!(report.seats[0].wins + report.seats[0].losses + report.seats[0].ties, 20);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Simulation {
+    num_rows: usize,
+    num_columns: usize,
+    amount_to_win: usize,
+    num_games: usize,
+    seed: Option<u64>,
+    seats: Vec<Seat>,
+    starting_player_index: Option<usize>,
+    time_control: Option<TimeControl>,
+    clock_scope: ClockScope,
+}
+
+impl Default for Simulation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Simulation {
+    /// Constructs a `Simulation` of 100 games on a 6x7 board with a win length of 4, a random
+    /// master seed and no seats.
+    pub fn new() -> Self {
+        Simulation {
+            num_rows: 6,
+            num_columns: 7,
+            amount_to_win: 4,
+            num_games: 100,
+            seed: None,
+            seats: Vec::new(),
+            starting_player_index: None,
+            time_control: None,
+            clock_scope: ClockScope::Player,
+        }
+    }
+
+    /// Sets the number of rows and columns of the board.
+    pub fn board_size(mut self, num_rows: usize, num_columns: usize) -> Self {
+        self.num_rows = num_rows;
+        self.num_columns = num_columns;
+        self
+    }
+
+    /// Sets the number of pieces a team must get in a row to win a game.
+    pub fn amount_to_win(mut self, amount_to_win: usize) -> Self {
+        self.amount_to_win = amount_to_win;
+        self
+    }
+
+    /// Sets the number of games to play.
+    pub fn games(mut self, num_games: usize) -> Self {
+        self.num_games = num_games;
+        self
+    }
+
+    /// Sets the master seed the seed of every game is derived from.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Adds a seat on the team of `color` whose player is constructed for every game by
+    /// `factory`.
+    pub fn seat<F>(mut self, color: Color, factory: F) -> Self
+    where
+        F: FnMut() -> Box<dyn Player> + 'static,
+    {
+        self.seats.push(Seat {
+            color,
+            factory: Box::new(factory),
+        });
+        self
+    }
+
+    /// Sets the index of the seat which moves first in every game, or `None` to rotate the
+    /// first move through the seats.
+    pub fn starting_player(mut self, starting_player_index: Option<usize>) -> Self {
+        self.starting_player_index = starting_player_index;
+        self
+    }
+
+    /// Times every game under `time_control` (see `GameBuilder::time_control`), so that players
+    /// can lose on time.
+    pub fn time_control(mut self, time_control: TimeControl) -> Self {
+        self.time_control = Some(time_control);
+        self
+    }
+
+    /// Sets whether every player or every team has a clock of their own in timed games.
+    pub fn clock_scope(mut self, clock_scope: ClockScope) -> Self {
+        self.clock_scope = clock_scope;
+        self
+    }
+
+    /// Plays every game of the simulation without any terminal output.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the `SimulationReport` or an `Err` with a
+    /// `Connect4Error` if the settings are invalid or a player fails.
+    pub fn run(mut self) -> Result<SimulationReport, Connect4Error> {
+        if self.num_games == 0 {
+            return Err(Connect4Error::InvalidConfig(
+                "The simulation must play at least one game.".into(),
+            ));
+        }
+        if self.seats.is_empty() {
+            return Err(Connect4Error::InvalidConfig(
+                "The simulation must have at least one seat.".into(),
+            ));
+        }
+        let seed = self.seed.unwrap_or_else(|| rand::thread_rng().gen());
+        let mut master_rng = StdRng::seed_from_u64(seed);
+        let player_colors: Vec<Color> = self.seats.iter().map(|seat| seat.color).collect();
+        let mut player_names = Vec::new();
+        let mut games = Vec::new();
+        for index in 0..self.num_games {
+            let game_seed: u64 = master_rng.gen();
+            let players: Vec<Box<dyn Player>> =
+                self.seats.iter_mut().map(|seat| (seat.factory)()).collect();
+            if player_names.is_empty() {
+                player_names = players.iter().map(|player| player.get_name()).collect();
+            }
+            let starting_player_index = self
+                .starting_player_index
+                .unwrap_or(index % self.seats.len());
+            let mut builder = GameBuilder::new()
+                .board_size(self.num_rows, self.num_columns)
+                .amount_to_win(self.amount_to_win)
+                .players(players, player_colors.clone())
+                .starting_player(starting_player_index)
+                .seed(game_seed);
+            if let Some(time_control) = self.time_control {
+                builder = builder
+                    .time_control(time_control)
+                    .clock_scope(self.clock_scope);
+            }
+            let mut game = builder.build()?;
+            let outcome = game.start()?;
+            games.push(GameLog {
+                index,
+                seed: game_seed,
+                starting_player_index,
+                moves: outcome.moves,
+                reason: outcome.reason,
+                winning_color: outcome.winning_color,
+            });
+        }
+        Ok(SimulationReport::new(
+            seed,
+            self.num_columns,
+            player_names,
+            player_colors,
+            games,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AIPlayer, Budget, Difficulty, MctsPlayer};
+
+    /// Returns a seeded simulation between an easy bot and an MCTS bot.
+    fn simulation(seed: u64) -> Simulation {
+        Simulation::new()
+            .board_size(5, 6)
+            .games(6)
+            .seed(seed)
+            .seat(Color::RED, || -> Box<dyn Player> {
+                Box::new(AIPlayer::with_difficulty("Easy".into(), Difficulty::Easy))
+            })
+            .seat(Color::BLACK, || -> Box<dyn Player> {
+                Box::new(MctsPlayer::new("MCTS".into(), Budget::Iterations(50)))
+            })
+    }
+
+    #[test]
+    fn seeded_runs_are_reproducible() {
+        let report = simulation(42).run().unwrap();
+        assert_eq!(report.seed, 42);
+        assert_eq!(report.games.len(), 6);
+        assert_eq!(simulation(42).run().unwrap(), report);
+        let other = simulation(43).run().unwrap();
+        assert_ne!(
+            other
+                .games
+                .iter()
+                .map(|game| &game.moves)
+                .collect::<Vec<_>>(),
+            report
+                .games
+                .iter()
+                .map(|game| &game.moves)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn the_first_move_rotates_through_the_seats() {
+        let report = simulation(42).run().unwrap();
+        let starts: Vec<usize> = report
+            .games
+            .iter()
+            .map(|game| game.starting_player_index)
+            .collect();
+        assert_eq!(starts, [0, 1, 0, 1, 0, 1]);
+        let report = simulation(42).starting_player(Some(1)).run().unwrap();
+        assert!(report
+            .games
+            .iter()
+            .all(|game| game.starting_player_index == 1));
+    }
+
+    #[test]
+    fn invalid_simulations_are_rejected() {
+        assert!(matches!(
+            simulation(42).games(0).run(),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            Simulation::new().run(),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,454 @@
+use crate::{format_moves, Color, EndReason};
+
+/// The log of a single game of a `Simulation`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameLog {
+    /// The 0-based number of the game within the simulation.
+    pub index: usize,
+    /// The seed the game was played with (see `GameBuilder::seed`).
+    pub seed: u64,
+    /// The index of the seat which moved first.
+    pub starting_player_index: usize,
+    /// The column indices of every move played in the game in order.
+    pub moves: Vec<usize>,
+    /// Why the game ended.
+    pub reason: EndReason,
+    /// The `Color` of the winning team, or `None` if nobody won.
+    pub winning_color: Option<Color>,
+}
+
+/// The results of a single seat over every game of a `Simulation`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeatStats {
+    /// The name of the seat's player.
+    pub name: String,
+    /// The color team of the seat.
+    pub color: Color,
+    /// The number of games won by the seat's team.
+    pub wins: usize,
+    /// The number of games won by another team or lost by the seat's team without a winner.
+    pub losses: usize,
+    /// The number of games which ended in a tie (see `EndReason::is_tie`).
+    pub ties: usize,
+    /// The number of games another team lost by resignation, time or forfeit without any team
+    /// winning, which count as neither a win, a loss nor a tie for the seat.
+    pub no_winner: usize,
+    /// The fraction of games won by the seat's team.
+    pub win_rate: f64,
+    /// The fraction of games which ended in a tie.
+    pub tie_rate: f64,
+    /// The number of games in which the seat moved first.
+    pub games_started: usize,
+    /// The number of games the seat's team won when the seat moved first.
+    pub wins_when_starting: usize,
+    /// The number of moves the seat played in each column.
+    pub column_counts: Vec<usize>,
+}
+
+/// The statistics of every game played by a `Simulation`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationReport {
+    /// The master seed of the simulation, which replays the same games when reused.
+    pub seed: u64,
+    /// The results of every seat in seating order.
+    pub seats: Vec<SeatStats>,
+    /// The number of games which ended in a tie (see `EndReason::is_tie`).
+    pub ties: usize,
+    /// The number of games a team lost by resignation, time or forfeit without any team
+    /// winning, such as a resignation in a game between three teams.
+    pub no_winner: usize,
+    /// The average number of moves per game.
+    pub average_length: f64,
+    /// The number of moves of the shortest game.
+    pub min_length: usize,
+    /// The number of moves of the longest game.
+    pub max_length: usize,
+    /// The fraction of games won by the team of the seat which moved first.
+    pub first_player_win_rate: f64,
+    /// The fraction of all moves played in each column.
+    pub column_frequency: Vec<f64>,
+    /// The log of every game in the order they were played.
+    pub games: Vec<GameLog>,
+}
+
+/// Returns `count / total`, or `0.0` if `total` is zero.
+fn rate(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+impl SimulationReport {
+    /// Computes the statistics of `games` played on a board with `num_columns` columns by
+    /// seats with the given names and colors.
+    pub(super) fn new(
+        seed: u64,
+        num_columns: usize,
+        player_names: Vec<String>,
+        player_colors: Vec<Color>,
+        games: Vec<GameLog>,
+    ) -> Self {
+        let num_seats = player_colors.len();
+        let mut seats: Vec<SeatStats> = player_names
+            .into_iter()
+            .zip(player_colors.iter())
+            .map(|(name, color)| SeatStats {
+                name,
+                color: *color,
+                wins: 0,
+                losses: 0,
+                ties: 0,
+                no_winner: 0,
+                win_rate: 0.0,
+                tie_rate: 0.0,
+                games_started: 0,
+                wins_when_starting: 0,
+                column_counts: vec![0; num_columns],
+            })
+            .collect();
+        let mut first_player_wins = 0;
+        for game in games.iter() {
+            let first_won = game.winning_color == Some(player_colors[game.starting_player_index]);
+            seats[game.starting_player_index].games_started += 1;
+            if first_won {
+                first_player_wins += 1;
+                seats[game.starting_player_index].wins_when_starting += 1;
+            }
+            let losing_color = game
+                .reason
+                .player_index()
+                .map(|player_index| player_colors[player_index]);
+            for seat in seats.iter_mut() {
+                match game.winning_color {
+                    Some(color) if color == seat.color => seat.wins += 1,
+                    Some(_) => seat.losses += 1,
+                    None if game.reason.is_tie() => seat.ties += 1,
+                    None if losing_color == Some(seat.color) => seat.losses += 1,
+                    None => seat.no_winner += 1,
+                }
+            }
+            for (i, col_index) in game.moves.iter().enumerate() {
+                let seat_index = (game.starting_player_index + i) % num_seats;
+                seats[seat_index].column_counts[*col_index] += 1;
+            }
+        }
+        for seat in seats.iter_mut() {
+            seat.win_rate = rate(seat.wins, games.len());
+            seat.tie_rate = rate(seat.ties, games.len());
+        }
+        let lengths = games.iter().map(|game| game.moves.len());
+        let total_moves: usize = lengths.clone().sum();
+        let column_frequency = (0..num_columns)
+            .map(|col_index| {
+                let count = seats.iter().map(|seat| seat.column_counts[col_index]).sum();
+                rate(count, total_moves)
+            })
+            .collect();
+        SimulationReport {
+            seed,
+            ties: games.iter().filter(|game| game.reason.is_tie()).count(),
+            no_winner: games
+                .iter()
+                .filter(|game| game.winning_color.is_none() && !game.reason.is_tie())
+                .count(),
+            average_length: rate(total_moves, games.len()),
+            min_length: lengths.clone().min().unwrap_or(0),
+            max_length: lengths.max().unwrap_or(0),
+            first_player_win_rate: rate(first_player_wins, games.len()),
+            column_frequency,
+            seats,
+            games,
+        }
+    }
+
+    /// Returns the per-seat statistics as CSV with a header row and one row per seat.
+    ///
+    /// The columns are the 1-based seat number, name, color, wins, losses, ties, games lost by
+    /// another team without a winner (`no_winner`), win and tie rates, the number of games the
+    /// seat moved first in, the seat's win rate in those games and the fraction of the seat's
+    /// moves played in each column (`col_1`, `col_2`, ...).
+    pub fn to_csv(&self) -> String {
+        let num_columns = self.column_frequency.len();
+        let mut csv = String::from(
+            "seat,name,color,wins,losses,ties,no_winner,win_rate,tie_rate,games_started,\
+             win_rate_when_starting",
+        );
+        for col_index in 0..num_columns {
+            csv += &format!(",col_{}", col_index + 1);
+        }
+        csv.push('\n');
+        for (i, seat) in self.seats.iter().enumerate() {
+            let seat_moves = seat.column_counts.iter().sum();
+            csv += &format!(
+                "{},{},{},{},{},{},{},{:.4},{:.4},{},{:.4}",
+                i + 1,
+                csv_field(&seat.name),
+                seat.color,
+                seat.wins,
+                seat.losses,
+                seat.ties,
+                seat.no_winner,
+                seat.win_rate,
+                seat.tie_rate,
+                seat.games_started,
+                rate(seat.wins_when_starting, seat.games_started)
+            );
+            for count in seat.column_counts.iter() {
+                csv += &format!(",{:.4}", rate(*count, seat_moves));
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Returns the log of every game as CSV with a header row and one row per game.
+    ///
+    /// The columns are the 0-based game number, the game's seed, the 1-based seat number which
+    /// moved first, the result (the `Display` form of its `EndReason`, as in `GameRecord`), the
+    /// winning color (empty if nobody won), the number of moves and the moves in the notation of
+    /// `format_moves`.
+    pub fn games_csv(&self) -> String {
+        let num_columns = self.column_frequency.len();
+        let mut csv = String::from("game,seed,start,result,winner,length,moves\n");
+        for game in self.games.iter() {
+            csv += &format!(
+                "{},{},{},{},{},{},{}\n",
+                game.index,
+                game.seed,
+                game.starting_player_index + 1,
+                game.reason,
+                game.winning_color
+                    .map(|color| color.to_string())
+                    .unwrap_or_default(),
+                game.moves.len(),
+                csv_field(&format_moves(&game.moves, num_columns))
+            );
+        }
+        csv
+    }
+}
+
+#[cfg(feature = "serde")]
+impl SimulationReport {
+    /// Returns a `Result` type with an `Ok` containing the report, including the log of every
+    /// game, as pretty printed JSON or an `Err` with a `Connect4Error::InvalidState` if the
+    /// report cannot be serialized.
+    pub fn to_json(&self) -> Result<String, crate::Connect4Error> {
+        serde_json::to_string_pretty(self).map_err(|e| {
+            crate::Connect4Error::InvalidState(format!("Could not write the report as JSON: {e}"))
+        })
+    }
+}
+
+/// Quotes `field` for CSV if it contains a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl std::fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let num_games = self.games.len();
+        writeln!(f, "{num_games} game(s) played with seed {}.", self.seed)?;
+        writeln!(
+            f,
+            "{:<4} {:<16} {:<6} {:>6} {:>6} {:>6} {:>8}",
+            "seat", "name", "color", "wins", "losses", "ties", "win rate"
+        )?;
+        for (i, seat) in self.seats.iter().enumerate() {
+            writeln!(
+                f,
+                "{:<4} {:<16} {:<6} {:>6} {:>6} {:>6} {:>7.1}%",
+                i + 1,
+                seat.name,
+                seat.color.to_string(),
+                seat.wins,
+                seat.losses,
+                seat.ties,
+                seat.win_rate * 100.0
+            )?;
+        }
+        writeln!(
+            f,
+            "Ties: {} ({:.1}%)",
+            self.ties,
+            rate(self.ties, num_games) * 100.0
+        )?;
+        if self.no_winner > 0 {
+            writeln!(
+                f,
+                "Lost without a winner: {} ({:.1}%)",
+                self.no_winner,
+                rate(self.no_winner, num_games) * 100.0
+            )?;
+        }
+        writeln!(
+            f,
+            "Game length: {:.1} moves on average (shortest {}, longest {})",
+            self.average_length, self.min_length, self.max_length
+        )?;
+        writeln!(
+            f,
+            "The first player's team won {:.1}% of games.",
+            self.first_player_win_rate * 100.0
+        )?;
+        write!(f, "Column frequency:")?;
+        for (col_index, frequency) in self.column_frequency.iter().enumerate() {
+            write!(f, " {}: {:.1}%", col_index + 1, frequency * 100.0)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the log of a game with the given seed, starting seat, moves and result.
+    fn log(
+        index: usize,
+        starting_player_index: usize,
+        moves: Vec<usize>,
+        reason: EndReason,
+        winning_color: Option<Color>,
+    ) -> GameLog {
+        GameLog {
+            index,
+            seed: 11 + index as u64,
+            starting_player_index,
+            moves,
+            reason,
+            winning_color,
+        }
+    }
+
+    /// Returns the report of a win, a resignation and a tie between two seats on a board with
+    /// 3 columns.
+    fn report() -> SimulationReport {
+        SimulationReport::new(
+            5,
+            3,
+            vec!["A".into(), "B, \"the bot\"".into()],
+            vec![Color::RED, Color::BLACK],
+            vec![
+                log(0, 0, vec![0, 1, 0], EndReason::Connected, Some(Color::RED)),
+                log(
+                    1,
+                    1,
+                    vec![2, 2],
+                    EndReason::Resignation(0),
+                    Some(Color::BLACK),
+                ),
+                log(2, 0, vec![1], EndReason::BoardFull, None),
+            ],
+        )
+    }
+
+    #[test]
+    fn results_are_counted_per_seat() {
+        let report = report();
+        assert_eq!(report.seed, 5);
+        assert_eq!(report.ties, 1);
+        for seat in report.seats.iter() {
+            assert_eq!((seat.wins, seat.losses, seat.ties), (1, 1, 1));
+            assert_eq!(seat.win_rate, 1.0 / 3.0);
+            assert_eq!(seat.tie_rate, 1.0 / 3.0);
+        }
+        let [a, b] = &report.seats[..] else {
+            panic!("expected two seats");
+        };
+        assert_eq!((a.games_started, a.wins_when_starting), (2, 1));
+        assert_eq!((b.games_started, b.wins_when_starting), (1, 1));
+        assert_eq!(report.first_player_win_rate, 2.0 / 3.0);
+    }
+
+    #[test]
+    fn losses_without_a_winner_are_no_ties() {
+        let colors = vec![Color::RED, Color::BLACK, Color::YELLOW];
+        let names = vec!["A".into(), "B".into(), "C".into()];
+        let games = vec![
+            log(0, 0, vec![0], EndReason::Resignation(1), None),
+            log(1, 0, vec![0, 1], EndReason::Agreement, None),
+        ];
+        let report = SimulationReport::new(5, 3, names, colors, games);
+        assert_eq!((report.ties, report.no_winner), (1, 1));
+        let counts = report
+            .seats
+            .iter()
+            .map(|seat| (seat.wins, seat.losses, seat.ties, seat.no_winner))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [(0, 0, 1, 1), (0, 1, 1, 0), (0, 0, 1, 1)]);
+        assert!(report
+            .to_string()
+            .contains("Lost without a winner: 1 (50.0%)"));
+    }
+
+    #[test]
+    fn moves_are_counted_per_seat_and_column() {
+        let report = report();
+        assert_eq!(report.seats[0].column_counts, [2, 1, 1]);
+        assert_eq!(report.seats[1].column_counts, [0, 1, 1]);
+        assert_eq!(report.column_frequency, [1.0 / 3.0; 3]);
+        assert_eq!(report.average_length, 2.0);
+        assert_eq!((report.min_length, report.max_length), (1, 3));
+    }
+
+    #[test]
+    fn empty_reports_have_zero_rates() {
+        let report = SimulationReport::new(5, 2, vec!["A".into()], vec![Color::RED], vec![]);
+        assert_eq!(report.seats[0].win_rate, 0.0);
+        assert_eq!(report.first_player_win_rate, 0.0);
+        assert_eq!(report.column_frequency, [0.0, 0.0]);
+        assert_eq!((report.min_length, report.max_length), (0, 0));
+    }
+
+    #[test]
+    fn seats_are_written_as_csv() {
+        assert_eq!(
+            report().to_csv(),
+            "seat,name,color,wins,losses,ties,no_winner,win_rate,tie_rate,games_started,\
+             win_rate_when_starting,col_1,col_2,col_3\n\
+             1,A,Red,1,1,1,0,0.3333,0.3333,2,0.5000,0.5000,0.2500,0.2500\n\
+             2,\"B, \"\"the bot\"\"\",Black,1,1,1,0,0.3333,0.3333,1,1.0000,0.0000,0.5000,0.5000\n"
+        );
+    }
+
+    #[test]
+    fn games_are_written_as_csv() {
+        assert_eq!(
+            report().games_csv(),
+            "game,seed,start,result,winner,length,moves\n\
+             0,11,1,connect,Red,3,121\n\
+             1,12,2,resign 1,Black,2,33\n\
+             2,13,1,tie,,1,2\n"
+        );
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("Bot 1"), "Bot 1");
+        assert_eq!(csv_field("4 10 4"), "4 10 4");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trips() {
+        let report = report();
+        let json = report.to_json().unwrap();
+        assert_eq!(
+            serde_json::from_str::<SimulationReport>(&json).unwrap(),
+            report
+        );
+    }
+}
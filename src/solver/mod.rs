@@ -0,0 +1,525 @@
+//! A perfect solver for the standard 7x6 board.
+
+mod position;
+use position::{column_mask, SolverPosition, CELLS, HEIGHT, WIDTH};
+
+mod transposition_table;
+use transposition_table::{TranspositionTable, DEFAULT_SIZE};
+
+use crate::{Board, Color, Connect4Error};
+use std::time::Instant;
+
+/// The lowest score a position can have.
+const MIN_SCORE: i32 = -CELLS / 2 + 3;
+
+/// The number of positions explored between checks of the deadline.
+const DEADLINE_CHECK_INTERVAL: u64 = 4096;
+
+/// The outcome of a position for the player to move when both sides play perfectly.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+/// The game-theoretic value of a position for the player to move.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Score {
+    /// The outcome of the game when both sides play perfectly.
+    pub outcome: Outcome,
+    /// The number of moves left until the game ends when both sides play perfectly, including
+    /// the final move. The winner ends the game as quickly as possible and the loser drags it
+    /// out as long as possible.
+    pub distance: usize,
+}
+
+impl Score {
+    /// Converts a raw negamax score of a position with `moves` pieces played into a `Score`.
+    ///
+    /// A positive raw score of `s` means the player to move wins with the move that leaves
+    /// `43 - 2s` or `42 - 2s` pieces on the board before it is played, a negative one that the
+    /// opponent does.
+    fn from_raw(raw: i32, moves: i32) -> Self {
+        if raw == 0 {
+            return Score {
+                outcome: Outcome::Draw,
+                distance: (CELLS - moves) as usize,
+            };
+        }
+        // the winner moves when the number of pieces played has this parity
+        let parity = if raw > 0 { moves % 2 } else { (moves + 1) % 2 };
+        let mut last_moves = CELLS + 1 - 2 * raw.abs();
+        if last_moves % 2 != parity {
+            last_moves -= 1;
+        }
+        Score {
+            outcome: if raw > 0 { Outcome::Win } else { Outcome::Loss },
+            distance: (last_moves - moves + 1) as usize,
+        }
+    }
+
+    /// Returns a number which is larger the better the score is for the player to move.
+    ///
+    /// Wins rank above draws and draws above losses; quicker wins and slower losses rank higher.
+    pub fn rank(&self) -> i64 {
+        let distance = self.distance as i64;
+        match self.outcome {
+            Outcome::Win => i64::MAX / 2 - distance,
+            Outcome::Draw => 0,
+            Outcome::Loss => i64::MIN / 2 + distance,
+        }
+    }
+}
+
+impl std::fmt::Display for Score {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.outcome {
+            Outcome::Win => write!(f, "win in {}", self.distance),
+            Outcome::Loss => write!(f, "loss in {}", self.distance),
+            Outcome::Draw => write!(f, "draw"),
+        }
+    }
+}
+
+/// Sorts up to `WIDTH` moves by their heuristic scores, returning the best first.
+struct MoveSorter {
+    entries: [(u64, u32); WIDTH],
+    len: usize,
+}
+
+impl MoveSorter {
+    fn new() -> Self {
+        MoveSorter {
+            entries: [(0, 0); WIDTH],
+            len: 0,
+        }
+    }
+
+    /// Adds a move, keeping the entries sorted so that equally scored moves added later are
+    /// returned first.
+    fn add(&mut self, move_bit: u64, score: u32) {
+        let mut index = self.len;
+        self.len += 1;
+        while index > 0 && self.entries[index - 1].1 > score {
+            self.entries[index] = self.entries[index - 1];
+            index -= 1;
+        }
+        self.entries[index] = (move_bit, score);
+    }
+
+    /// Removes and returns the best remaining move.
+    fn next(&mut self) -> Option<u64> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.entries[self.len].0)
+    }
+}
+
+/// A perfect-play solver for the standard 7x6 board with a win length of 4.
+///
+/// Positions are searched with a negamax search using alpha-beta pruning, anticipation of the
+/// opponent's immediate wins and moves ordered by the threats they create. Results are cached in
+/// a transposition table shared by mirrored positions, which is kept across calls so that
+/// solving successive positions of the same game gets cheaper.
+pub struct Solver {
+    table: TranspositionTable,
+    /// The column indices in the order they are searched in, center first.
+    column_order: [usize; WIDTH],
+    node_count: u64,
+    /// The point in time the current search has to stop at, if limited.
+    deadline: Option<Instant>,
+    /// Whether the deadline passed during the current search.
+    aborted: bool,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solver {
+    /// Constructs a `Solver` with an empty transposition table.
+    pub fn new() -> Self {
+        let mut column_order = [0; WIDTH];
+        for (i, col_index) in column_order.iter_mut().enumerate() {
+            *col_index =
+                (WIDTH as isize / 2 + (1 - 2 * (i as isize % 2)) * (i as isize + 1) / 2) as usize;
+        }
+        Solver {
+            table: TranspositionTable::new(DEFAULT_SIZE),
+            column_order,
+            node_count: 0,
+            deadline: None,
+            aborted: false,
+        }
+    }
+
+    /// Returns the number of positions explored since the solver was constructed or reset.
+    pub fn node_count(&self) -> u64 {
+        self.node_count
+    }
+
+    /// Clears the transposition table and the node count.
+    pub fn reset(&mut self) {
+        self.table.reset();
+        self.node_count = 0;
+    }
+
+    /// Returns the raw score of `position` within the window `alpha`..`beta`, assuming the
+    /// player to move cannot win with their next move.
+    ///
+    /// If the deadline passes, the search is aborted and the returned score is meaningless.
+    fn negamax(&mut self, position: &SolverPosition, mut alpha: i32, mut beta: i32) -> i32 {
+        self.node_count += 1;
+        if self.node_count.is_multiple_of(DEADLINE_CHECK_INTERVAL)
+            && self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            self.aborted = true;
+        }
+        if self.aborted {
+            return 0;
+        }
+        let next = position.possible_non_losing_moves();
+        if next == 0 {
+            // every move lets the opponent win right away
+            return -(CELLS - position.moves()) / 2;
+        }
+        if position.moves() >= CELLS - 2 {
+            // neither player can win with the last two pieces
+            return 0;
+        }
+        let min = -(CELLS - 2 - position.moves()) / 2;
+        if alpha < min {
+            alpha = min;
+            if alpha >= beta {
+                return alpha;
+            }
+        }
+        let key = position.key();
+        let max = match self.table.get(key) {
+            0 => (CELLS - 1 - position.moves()) / 2,
+            value => value as i32 + MIN_SCORE - 1,
+        };
+        if beta > max {
+            beta = max;
+            if alpha >= beta {
+                return beta;
+            }
+        }
+        let mut moves = MoveSorter::new();
+        for col_index in self.column_order.iter().rev() {
+            let move_bit = next & column_mask(*col_index);
+            if move_bit != 0 {
+                moves.add(move_bit, position.move_score(move_bit));
+            }
+        }
+        while let Some(move_bit) = moves.next() {
+            let mut child = *position;
+            child.play(move_bit);
+            let score = -self.negamax(&child, -beta, -alpha);
+            if self.aborted {
+                return 0;
+            }
+            if score >= beta {
+                return score;
+            }
+            alpha = alpha.max(score);
+        }
+        self.table.put(key, (alpha - MIN_SCORE + 1) as u8);
+        alpha
+    }
+
+    /// Returns the exact raw score of `position` by narrowing the score window with null-window
+    /// searches.
+    fn solve_position(&mut self, position: &SolverPosition) -> i32 {
+        if position.can_win_next() {
+            return (CELLS + 1 - position.moves()) / 2;
+        }
+        if position.moves() >= CELLS {
+            return 0;
+        }
+        let mut min = -(CELLS - position.moves()) / 2;
+        let mut max = (CELLS + 1 - position.moves()) / 2;
+        while min < max {
+            let mut med = min + (max - min) / 2;
+            // probe closer to zero first, where most scores lie
+            if med <= 0 && min / 2 < med {
+                med = min / 2;
+            } else if med >= 0 && max / 2 > med {
+                med = max / 2;
+            }
+            let score = self.negamax(position, med, med + 1);
+            if self.aborted {
+                return 0;
+            }
+            if score <= med {
+                max = score;
+            } else {
+                min = score;
+            }
+        }
+        min
+    }
+
+    /// Converts `board` into a `SolverPosition`, checking that it is a standard, undecided board.
+    fn to_position(board: &Board, to_move: Color) -> Result<SolverPosition, Connect4Error> {
+        let position = SolverPosition::from_board(board, to_move)?;
+        if board.get_winning_color(4).is_some() {
+            return Err(Connect4Error::InvalidState(
+                "The position has already been won.".into(),
+            ));
+        }
+        Ok(position)
+    }
+
+    /// Returns the game-theoretic `Score` of `board` for the player of `Color` `to_move`,
+    /// assuming the two colors alternate turns from here on.
+    ///
+    /// Returns a `Result` type with an `Err` containing a `Connect4Error` if the board is not a
+    /// standard 7x6 board, holds the pieces of more than two teams or has already been won.
+    pub fn solve(&mut self, board: &Board, to_move: Color) -> Result<Score, Connect4Error> {
+        let position = Self::to_position(board, to_move)?;
+        self.deadline = None;
+        self.aborted = false;
+        let raw = self.solve_position(&position);
+        Ok(Score::from_raw(raw, position.moves()))
+    }
+
+    /// Returns the `Score` for the player of `Color` `to_move` of dropping a piece in each column
+    /// of `board`, or `None` for columns which are full.
+    ///
+    /// Returns a `Result` type with an `Err` containing a `Connect4Error` if the board is not a
+    /// standard 7x6 board, holds the pieces of more than two teams or has already been won.
+    pub fn analyze(
+        &mut self,
+        board: &Board,
+        to_move: Color,
+    ) -> Result<Vec<Option<Score>>, Connect4Error> {
+        self.analyze_position(board, to_move, None)
+            .map(|scores| scores.unwrap_or_default())
+    }
+
+    /// Like `analyze`, but gives up once `deadline` passes.
+    ///
+    /// Returns a `Result` type with an `Ok` containing the scores, or `None` if the deadline
+    /// passed first, or an `Err` with a `Connect4Error` as for `analyze`. Positions solved before
+    /// the deadline are kept in the transposition table, so trying again later resumes the work.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use connect_4::{Board, Color, Solver};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// # fn main() -> Result<(), connect_4::Connect4Error> {
+    /// let board = Board::from_moves("2252576253462244111563365343671351441", 6, 7)?;
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// let scores = Solver::new().analyze_until(&board, Color::BLACK, deadline)?;
+    /// assert!(scores.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn analyze_until(
+        &mut self,
+        board: &Board,
+        to_move: Color,
+        deadline: Instant,
+    ) -> Result<Option<Vec<Option<Score>>>, Connect4Error> {
+        self.analyze_position(board, to_move, Some(deadline))
+    }
+
+    /// Analyzes `board` as described by `analyze`, giving up with `None` once `deadline` passes.
+    fn analyze_position(
+        &mut self,
+        board: &Board,
+        to_move: Color,
+        deadline: Option<Instant>,
+    ) -> Result<Option<Vec<Option<Score>>>, Connect4Error> {
+        let position = Self::to_position(board, to_move)?;
+        self.deadline = deadline;
+        self.aborted = false;
+        let mut scores: Vec<Option<Score>> = vec![None; WIDTH];
+        for col_index in 0..WIDTH {
+            let mirrored = WIDTH - 1 - col_index;
+            if position.is_symmetric() && mirrored < col_index {
+                scores[col_index] = scores[mirrored];
+                continue;
+            }
+            let move_bit = position.possible() & column_mask(col_index);
+            if move_bit == 0 {
+                continue;
+            }
+            let mut child = position;
+            child.play(move_bit);
+            let raw = if position.move_wins(move_bit) {
+                (CELLS + 1 - position.moves()) / 2
+            } else {
+                -self.solve_position(&child)
+            };
+            if self.aborted {
+                return Ok(None);
+            }
+            scores[col_index] = Some(Score::from_raw(raw, position.moves()));
+        }
+        Ok(Some(scores))
+    }
+}
+
+/// Returns the game-theoretic `Score` of `board` for the player of `Color` `to_move` using a
+/// freshly constructed `Solver`.
+///
+/// See `Solver::solve` for details.
+pub fn solve(board: &Board, to_move: Color) -> Result<Score, Connect4Error> {
+    Solver::new().solve(board, to_move)
+}
+
+/// Returns `true` if `board` has the dimensions the solver supports.
+pub fn is_standard(board: &Board) -> bool {
+    board.num_rows == HEIGHT && board.num_columns == WIDTH
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Returns the color to move after `moves` in a game between red and black.
+    fn to_move(moves: &str) -> Color {
+        [Color::RED, Color::BLACK][moves.len() % 2]
+    }
+
+    /// Returns a board of the given size with the 1-based column digits of `moves` played by
+    /// alternating colors, starting with red.
+    fn from_moves(moves: &str, num_rows: usize, num_columns: usize) -> Board {
+        let mut board = Board::new(num_rows, num_columns, 3, 7);
+        for (turn, digit) in moves.chars().enumerate() {
+            let col_index = digit.to_digit(10).unwrap() as usize - 1;
+            board
+                .drop_piece([Color::RED, Color::BLACK][turn % 2], col_index)
+                .unwrap();
+        }
+        board
+    }
+
+    /// Returns the `Score` of `board` for `color` by searching every line of play to the end.
+    fn brute_force(board: &mut Board, color: Color, other: Color) -> Score {
+        let mut best: Option<Score> = None;
+        for col_index in 0..WIDTH {
+            if !board.available_column(col_index) {
+                continue;
+            }
+            board.drop_piece(color, col_index).unwrap();
+            let score = if board.get_winning_color(4) == Some(color) {
+                Score {
+                    outcome: Outcome::Win,
+                    distance: 1,
+                }
+            } else if board.is_full() {
+                Score {
+                    outcome: Outcome::Draw,
+                    distance: 1,
+                }
+            } else {
+                let reply = brute_force(board, other, color);
+                Score {
+                    outcome: match reply.outcome {
+                        Outcome::Win => Outcome::Loss,
+                        Outcome::Loss => Outcome::Win,
+                        Outcome::Draw => Outcome::Draw,
+                    },
+                    distance: reply.distance + 1,
+                }
+            };
+            board.undo_piece(col_index).unwrap();
+            if best.is_none_or(|best| score.rank() > best.rank()) {
+                best = Some(score);
+            }
+        }
+        best.unwrap()
+    }
+
+    #[test]
+    fn solve_gives_known_scores() {
+        let cases = [
+            ("2252576253462244111563365343671351441", Outcome::Loss, 4),
+            ("7422341735647741166133573473242566", Outcome::Win, 7),
+            ("112426734151761444336747227515", Outcome::Draw, 12),
+        ];
+        let mut solver = Solver::new();
+        for (moves, outcome, distance) in cases {
+            let mut board = from_moves(moves, HEIGHT, WIDTH);
+            let color = to_move(moves);
+            let expected = Score { outcome, distance };
+            assert_eq!(solver.solve(&board, color).unwrap(), expected, "{moves}");
+            let other = to_move(&format!("{moves}0"));
+            assert_eq!(brute_force(&mut board, color, other), expected, "{moves}");
+        }
+    }
+
+    #[test]
+    fn solve_agrees_with_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut solver = Solver::new();
+        let mut checked = 0;
+        while checked < 20 {
+            let mut moves = String::new();
+            let mut board = Board::new(HEIGHT, WIDTH, 3, 7);
+            while moves.len() < 31 {
+                let col_index = rng.gen_range(0..WIDTH);
+                if board.available_column(col_index) {
+                    board.drop_piece(to_move(&moves), col_index).unwrap();
+                    moves.push_str(&(col_index + 1).to_string());
+                }
+            }
+            if board.get_winning_color(4).is_some() {
+                continue;
+            }
+            let color = to_move(&moves);
+            let other = to_move(&format!("{moves}0"));
+            let expected = brute_force(&mut board.clone(), color, other);
+            assert_eq!(solver.solve(&board, color).unwrap(), expected, "{moves}");
+            let scores = solver.analyze(&board, color).unwrap();
+            let best = scores.iter().flatten().max_by_key(|score| score.rank());
+            assert_eq!(best, Some(&expected), "{moves}");
+            checked += 1;
+        }
+    }
+
+    #[test]
+    fn deadlines_abort_the_search() {
+        let mut solver = Solver::new();
+        let board = from_moves("44", HEIGHT, WIDTH);
+        let passed = Instant::now();
+        assert_eq!(
+            solver.analyze_until(&board, Color::RED, passed).unwrap(),
+            None
+        );
+        let board = from_moves("2252576253462244111563365343671351441", HEIGHT, WIDTH);
+        let deadline = Instant::now() + std::time::Duration::from_secs(60);
+        assert_eq!(
+            solver
+                .analyze_until(&board, Color::BLACK, deadline)
+                .unwrap(),
+            Some(solver.analyze(&board, Color::BLACK).unwrap())
+        );
+    }
+
+    #[test]
+    fn unsupported_positions_are_rejected() {
+        assert!(matches!(
+            solve(&from_moves("1234", 4, 5), Color::RED),
+            Err(Connect4Error::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            solve(&from_moves("1212121", HEIGHT, WIDTH), Color::BLACK),
+            Err(Connect4Error::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{Board, Color, Connect4Error};
+
+/// The number of columns of the standard board the solver works on.
+pub const WIDTH: usize = 7;
+/// The number of rows of the standard board the solver works on.
+pub const HEIGHT: usize = 6;
+/// The number of cells of the standard board.
+pub const CELLS: i32 = (WIDTH * HEIGHT) as i32;
+
+/// Returns a mask with a single bit set in the bottom cell of every column.
+const fn bottom_mask() -> u64 {
+    let mut mask = 0;
+    let mut col_index = 0;
+    while col_index < WIDTH {
+        mask |= 1 << (col_index * (HEIGHT + 1));
+        col_index += 1;
+    }
+    mask
+}
+
+const BOTTOM_MASK: u64 = bottom_mask();
+const BOARD_MASK: u64 = BOTTOM_MASK * ((1 << HEIGHT) - 1);
+
+/// Returns a mask of every cell in the column with index `col_index`.
+pub const fn column_mask(col_index: usize) -> u64 {
+    ((1 << HEIGHT) - 1) << (col_index * (HEIGHT + 1))
+}
+
+/// A 7x6 position stored as two `u64` bitboards, laid out like a `BitBoard` of the same size.
+///
+/// Only the pieces of the player to move and the occupied cells are stored, which makes playing
+/// a move a matter of swapping sides and setting a single bit.
+#[derive(Debug, Clone, Copy)]
+pub struct SolverPosition {
+    /// The cells occupied by the player to move.
+    current: u64,
+    /// The cells occupied by either player.
+    mask: u64,
+    /// The number of pieces played so far.
+    moves: i32,
+}
+
+impl SolverPosition {
+    /// Converts a standard sized `Board` into a `SolverPosition` with `to_move` as the player to
+    /// move.
+    ///
+    /// Returns a `Result` type with an `Err` containing a `Connect4Error` if the board is not a
+    /// standard 7x6 board or holds the pieces of more than two teams.
+    pub fn from_board(board: &Board, to_move: Color) -> Result<Self, Connect4Error> {
+        let bits = match board.bitboard() {
+            Some(bits) if board.num_rows == HEIGHT && board.num_columns == WIDTH => bits,
+            _ => {
+                return Err(Connect4Error::InvalidConfig(format!(
+                    "The solver only supports {}x{} boards.",
+                    WIDTH, HEIGHT
+                )))
+            }
+        };
+        let num_teams = Color::ALL
+            .into_iter()
+            .filter(|color| *color == to_move || bits.get_mask(*color) != 0)
+            .count();
+        if num_teams > 2 {
+            return Err(Connect4Error::InvalidConfig(
+                "The solver only supports games between two teams.".into(),
+            ));
+        }
+        let mask = bits.get_occupied() as u64;
+        Ok(SolverPosition {
+            current: bits.get_mask(to_move) as u64,
+            mask,
+            moves: mask.count_ones() as i32,
+        })
+    }
+
+    /// Returns the number of pieces played so far.
+    pub fn moves(&self) -> i32 {
+        self.moves
+    }
+
+    /// Returns the key identifying the position, which is the same for mirrored positions.
+    pub fn key(&self) -> u64 {
+        let key = self.current + self.mask;
+        let mirrored = mirror(self.current) + mirror(self.mask);
+        key.min(mirrored)
+    }
+
+    /// Returns `true` if the position is identical to its mirror image.
+    pub fn is_symmetric(&self) -> bool {
+        mirror(self.current) == self.current && mirror(self.mask) == self.mask
+    }
+
+    /// Plays the move with the single bit `move_bit` for the player to move.
+    pub fn play(&mut self, move_bit: u64) {
+        self.current ^= self.mask;
+        self.mask |= move_bit;
+        self.moves += 1;
+    }
+
+    /// Returns a mask of the cells the player to move can play in.
+    pub fn possible(&self) -> u64 {
+        (self.mask + BOTTOM_MASK) & BOARD_MASK
+    }
+
+    /// Returns `true` if the player to move can win with their next move.
+    pub fn can_win_next(&self) -> bool {
+        self.winning_position() & self.possible() != 0
+    }
+
+    /// Returns `true` if playing `move_bit` wins the game for the player to move.
+    pub fn move_wins(&self, move_bit: u64) -> bool {
+        self.winning_position() & move_bit != 0
+    }
+
+    /// Returns a mask of the playable cells which do not let the opponent win right away.
+    pub fn possible_non_losing_moves(&self) -> u64 {
+        let mut possible = self.possible();
+        let opponent_win = self.opponent_winning_position();
+        let forced = possible & opponent_win;
+        if forced != 0 {
+            if forced & (forced - 1) != 0 {
+                // the opponent has two immediate wins, which cannot both be blocked
+                return 0;
+            }
+            possible = forced;
+        }
+        // never play directly below a cell the opponent wins in
+        possible & !(opponent_win >> 1)
+    }
+
+    /// Returns a heuristic score of `move_bit` counting the winning cells it creates.
+    pub fn move_score(&self, move_bit: u64) -> u32 {
+        winning_cells(self.current | move_bit, self.mask).count_ones()
+    }
+
+    /// Returns a mask of the empty cells which would complete a run for the player to move.
+    fn winning_position(&self) -> u64 {
+        winning_cells(self.current, self.mask)
+    }
+
+    /// Returns a mask of the empty cells which would complete a run for the opponent.
+    fn opponent_winning_position(&self) -> u64 {
+        winning_cells(self.current ^ self.mask, self.mask)
+    }
+}
+
+/// Returns the bitboard `bits` with its columns in reverse order.
+fn mirror(bits: u64) -> u64 {
+    (0..WIDTH).fold(0, |acc, col_index| {
+        let column = (bits & column_mask(col_index)) >> (col_index * (HEIGHT + 1));
+        acc | column << ((WIDTH - 1 - col_index) * (HEIGHT + 1))
+    })
+}
+
+/// Returns a mask of the empty cells which would complete a run of four for `position`.
+fn winning_cells(position: u64, mask: u64) -> u64 {
+    // vertical
+    let mut cells = (position << 1) & (position << 2) & (position << 3);
+    // horizontal and both diagonals
+    for shift in [HEIGHT + 1, HEIGHT, HEIGHT + 2] {
+        let pair = (position << shift) & (position << (2 * shift));
+        cells |= pair & (position << (3 * shift));
+        cells |= pair & (position >> shift);
+        let pair = (position >> shift) & (position >> (2 * shift));
+        cells |= pair & (position << shift);
+        cells |= pair & (position >> (3 * shift));
+    }
+    cells & (BOARD_MASK ^ mask)
+}
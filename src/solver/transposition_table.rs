@@ -0,0 +1,48 @@
+/// The default number of entries, a prime slightly above 2^23.
+pub const DEFAULT_SIZE: usize = (1 << 23) + 9;
+
+/// A fixed size hash table caching upper bounds of solved positions.
+///
+/// Only the lower 32 bits of each key are stored; since position keys fit in 49 bits and the
+/// table size is larger than 2^17, the remainder of the key is implied by the entry's index.
+pub struct TranspositionTable {
+    keys: Vec<u32>,
+    values: Vec<u8>,
+}
+
+impl TranspositionTable {
+    /// Constructs an empty `TranspositionTable` with `size` entries.
+    pub fn new(size: usize) -> Self {
+        TranspositionTable {
+            keys: vec![0; size],
+            values: vec![0; size],
+        }
+    }
+
+    fn index(&self, key: u64) -> usize {
+        (key % self.keys.len() as u64) as usize
+    }
+
+    /// Stores a non-zero `value` for `key`, overwriting whichever entry it collides with.
+    pub fn put(&mut self, key: u64, value: u8) {
+        let index = self.index(key);
+        self.keys[index] = key as u32;
+        self.values[index] = value;
+    }
+
+    /// Returns the value stored for `key` or `0` if there is none.
+    pub fn get(&self, key: u64) -> u8 {
+        let index = self.index(key);
+        if self.keys[index] == key as u32 {
+            self.values[index]
+        } else {
+            0
+        }
+    }
+
+    /// Removes every entry from the table.
+    pub fn reset(&mut self) {
+        self.keys.fill(0);
+        self.values.fill(0);
+    }
+}